@@ -1,3 +1,5 @@
+pub mod report;
+
 #[macro_export]
 macro_rules! delim {
     () => {
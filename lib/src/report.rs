@@ -0,0 +1,210 @@
+//! Printing an error together with everything that caused it.
+//!
+//! `{:?}` on an error shows only that error, and `{}` shows only its message.
+//! `Report` also follows `Error::source()`, the way a `main` returning
+//! `anyhow::Result` does:
+//!
+//! ```text
+//! Error: cannot load the config
+//!
+//! Caused by:
+//!     1: cannot read settings.toml
+//!     2: No such file or directory (os error 2)
+//! ```
+
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    env,
+    error::Error,
+    fmt,
+    io::{self, IsTerminal},
+};
+
+/// Whether a `Report` uses ANSI colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    #[default]
+    Plain,
+    Colored,
+}
+
+impl Style {
+    /// `Colored` when stderr is a terminal and `NO_COLOR` is not set.
+    pub fn detect() -> Self {
+        if io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none() {
+            Style::Colored
+        } else {
+            Style::Plain
+        }
+    }
+
+    fn paint(self, code: &str, text: &str) -> String {
+        match self {
+            Style::Plain => text.to_string(),
+            Style::Colored => format!("\x1b[{code}m{text}\x1b[0m"),
+        }
+    }
+}
+
+/// An error and its chain of sources, ready to be displayed.
+pub struct Report<'a> {
+    error: &'a (dyn Error + 'static),
+    backtrace: Option<Backtrace>,
+    style: Style,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(error: &'a (dyn Error + 'static)) -> Self {
+        Self {
+            error,
+            backtrace: None,
+            style: Style::Plain,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Shows `backtrace` after the causes.
+    pub fn backtrace(mut self, backtrace: Backtrace) -> Self {
+        self.backtrace = Some(backtrace);
+        self
+    }
+
+    /**
+     * Shows a backtrace of the current call stack after the causes.
+     *
+     * Like any `Backtrace::capture`, it is only captured when
+     * `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` is set.
+     */
+    pub fn capture_backtrace(self) -> Self {
+        self.backtrace(Backtrace::capture())
+    }
+
+    /// The error followed by its sources, outermost first.
+    pub fn chain(&self) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+        std::iter::successors(Some(self.error), |e| (*e).source())
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = self.style;
+        write!(f, "{} {}", style.paint("1;31", "Error:"), self.error)?;
+
+        let causes: Vec<_> = self.chain().skip(1).collect();
+        if !causes.is_empty() {
+            write!(f, "\n\n{}", style.paint("1;33", "Caused by:"))?;
+            let width = causes.len().to_string().len();
+            for (i, cause) in causes.iter().enumerate() {
+                let number = format!("{:>width$}:", i + 1);
+                write!(f, "\n    {} {cause}", style.paint("2", &number))?;
+            }
+        }
+
+        if let Some(backtrace) = &self.backtrace {
+            write!(f, "\n\n{}", style.paint("1;36", "Backtrace:"))?;
+            match backtrace.status() {
+                BacktraceStatus::Captured => write!(f, "\n{backtrace}")?,
+                _ => write!(f, " not captured, set RUST_BACKTRACE=1 to see one")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Prints the report of `error` to stderr, colored if stderr is a terminal.
+pub fn eprint_report(error: &(dyn Error + 'static)) {
+    eprintln!("{}", Report::new(error).style(Style::detect()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Wrapped {
+        message: &'static str,
+        source: Option<Box<dyn Error>>,
+    }
+
+    impl fmt::Display for Wrapped {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.message)
+        }
+    }
+
+    impl Error for Wrapped {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.source.as_deref()
+        }
+    }
+
+    fn wrap(message: &'static str, source: impl Error + 'static) -> Wrapped {
+        Wrapped {
+            message,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    #[test]
+    fn should_number_the_causes() {
+        let inner = "x".parse::<i32>().unwrap_err();
+        let error = wrap("cannot load the config", wrap("bad port", inner));
+
+        assert_eq!(
+            Report::new(&error).to_string(),
+            "Error: cannot load the config\n\
+             \n\
+             Caused by:\n    \
+             1: bad port\n    \
+             2: invalid digit found in string"
+        );
+        assert_eq!(Report::new(&error).chain().count(), 3);
+    }
+
+    #[test]
+    fn should_show_a_lone_error_on_one_line() {
+        let error = "x".parse::<i32>().unwrap_err();
+
+        assert_eq!(
+            Report::new(&error).to_string(),
+            "Error: invalid digit found in string"
+        );
+        assert_eq!(
+            Report::new(&error).style(Style::Colored).to_string(),
+            "\x1b[1;31mError:\x1b[0m invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn should_align_numbers_of_long_chains() {
+        let mut error = wrap("0", io::Error::other("root"));
+        for _ in 0..10 {
+            error = wrap("n", error);
+        }
+        let report = Report::new(&error).to_string();
+
+        assert!(report.contains("\n     1: n\n"));
+        assert!(report.ends_with("\n    10: 0\n    11: root"));
+    }
+
+    #[test]
+    fn should_mention_a_disabled_backtrace() {
+        let error = io::Error::other("boom");
+        let report = Report::new(&error).backtrace(Backtrace::disabled());
+
+        assert_eq!(
+            report.to_string(),
+            "Error: boom\n\nBacktrace: not captured, set RUST_BACKTRACE=1 to see one"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 // Make me compile without changing the function signature!
 
-#[cfg(feature = "skip")]
+#[cfg(feature = "exercise-strings1")]
 #[cfg(test)]
 mod tests {
     use super::*;
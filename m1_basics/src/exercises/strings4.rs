@@ -11,7 +11,7 @@ fn string(arg: String) {
     println!("{}", arg);
 }
 
-#[cfg(feature = "skip")]
+#[cfg(feature = "exercise-strings4")]
 #[test]
 fn test() {
     ______("blue");
@@ -1,6 +1,6 @@
 // Make this compile.
 
-#[cfg(feature = "skip")]
+#[cfg(feature = "exercise-move_semantics1")]
 #[cfg(test)]
 mod tests {
     use super::*;
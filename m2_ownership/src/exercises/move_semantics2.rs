@@ -1,6 +1,6 @@
 // Make the test pass by finding a way to keep both Vecs separate!
 
-#[cfg(feature = "skip")]
+#[cfg(feature = "exercise-move_semantics2")]
 #[cfg(test)]
 mod tests {
     use super::*;
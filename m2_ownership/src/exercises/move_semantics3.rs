@@ -1,7 +1,7 @@
 // Make me compile without adding new lines -- just changing existing lines! (no
 // lines with multiple semicolons necessary!)
 
-#[cfg(feature = "skip")]
+#[cfg(feature = "exercise-move_semantics3")]
 #[cfg(test)]
 mod tests {
     use super::*;
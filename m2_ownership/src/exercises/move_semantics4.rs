@@ -1,7 +1,7 @@
 // Make me compile only by reordering the lines in `test()`, but without
 // adding, changing or removing any of them.
 
-#[cfg(feature = "skip")]
+#[cfg(feature = "exercise-move_semantics4")]
 #[test]
 fn test1() {
     let mut x = 100;
@@ -1,6 +1,6 @@
 // You can't change anything except adding or removing references.
 
-#[cfg(featuer = "skip")]
+#[cfg(feature = "exercise-move_semantics5")]
 #[cfg(test)]
 mod tests {
     #[test]
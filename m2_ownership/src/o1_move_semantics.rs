@@ -20,7 +20,7 @@ pub fn move_ownership_on_assignment() {
     let u2 = u1; // `u1` is moved to `u2`
     println!("{u2:?}");
 
-    // this is an error
+    // this is an error (testing/compile_fail/m2_ownership/use_after_move_on_assignment.rs)
     // println!("{:?}", u1);
 }
 
@@ -33,7 +33,7 @@ pub fn move_ownership_on_parameter_passing() {
     let u = User { id: 9000 };
     print_user(u); // `u` is moved into the function
 
-    // this is an error
+    // this is an error (testing/compile_fail/m2_ownership/use_after_move_into_function.rs)
     // println!("{u:?}");
 }
 
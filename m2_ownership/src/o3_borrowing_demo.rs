@@ -25,16 +25,19 @@ fn borrow_test1() {
     let mut b1 = 1;
     let b2 = &mut b1;
     // let b3 = &mut b1; // Fail. Cannot mutably borrow when already mutably borrowed
+    //                   // (testing/compile_fail/m2_ownership/two_mutable_borrows.rs)
     // println!("{b1:?} {b2:?} {b3:?}");
 
     let mut c1 = 1;
     let c2 = &c1;
     // let c3 = &mut c1; // Fail. Cannot mutably borrow when already borrowed
+    //                   // (testing/compile_fail/m2_ownership/mutable_borrow_while_borrowed.rs)
     // println!("{c1:?} {c2:?} {c3:?}");
 
     let mut d1 = 1;
     let d2 = &mut d1;
     // let d3 = &d1; // Fail. Cannot borrow when already mutably borrowed
+    //               // (testing/compile_fail/m2_ownership/borrow_while_mutably_borrowed.rs)
     // println!("{d1:?} {d2:?} {d3:?}");
 }
 
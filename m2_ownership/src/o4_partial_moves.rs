@@ -32,6 +32,8 @@ fn partial_move_test1() {
  * line to print `user.bookmarks` instead of `user`, the code works:
  *
  *  println!("{:?} {:?}", user.bookmarks, config);
+ *
+ * (testing/compile_fail/m2_ownership/use_of_partially_moved_value.rs)
  */
 
 /*
@@ -50,6 +52,7 @@ fn mutability_is_inherited() {
     };
 
     // will not work as user isn't mutable
+    // (testing/compile_fail/m2_ownership/mutability_is_inherited.rs)
     // user.bookmarks.push("https://www.openmymind.net".to_owned());
 }
 
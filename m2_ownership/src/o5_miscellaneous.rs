@@ -1,21 +1,15 @@
 
-#[cfg(feature = "skip")]
-#[test]
-fn move_when_pattern_matching() {
-    let name: Option<String> = Some("Leto".to_string());
-
-    // if name.is_none() {
-    //     println!("no name");
-    // } else {
-    //     println!("we have a name: {}", name.unwrap());
-    // }
-
-    match name {
-        None => println!("no name"),
-        Some(name) => println!("we have a name: {}", name),
-    }
-    println!("{:?}", name)
-}
+// Compile-fail test: testing/compile_fail/m2_ownership/move_when_pattern_matching.rs
+//
+// fn move_when_pattern_matching() {
+//     let name: Option<String> = Some("Leto".to_string());
+//
+//     match name {
+//         None => println!("no name"),
+//         Some(name) => println!("we have a name: {}", name),
+//     }
+//     println!("{:?}", name)
+// }
 
 /*
  * The compiler will tell us that our last line is invalid because `name`
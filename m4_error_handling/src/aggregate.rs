@@ -0,0 +1,468 @@
+//! Parse a list of strings and aggregate the numbers, generalizing the
+//! `sum_of_vecs` exercises.
+//!
+//! `aggregate::<T, A>` parses every input as a `T` and folds the values in
+//! an accumulator of type `A`, e.g. `aggregate::<i32, i64>(inputs, Op::Sum)`
+//! sums `i32`s without overflowing as early. Failing fast, it reports the
+//! first input that does not parse or that makes the accumulator overflow.
+//! `aggregate_all` instead skips the inputs that do not parse and reports
+//! them all at the end, along with the aggregate of the others.
+
+use std::{error, fmt, str::FromStr};
+use thiserror::Error;
+
+/// An accumulator type, with the arithmetic `aggregate` needs.
+pub trait Number: Copy + PartialOrd {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// `None` if the result does not fit.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// `None` if the result does not fit.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// The mean of `count` values adding up to `self`. Integers round toward
+    /// zero; aggregate into a float for the exact mean.
+    fn mean(self, count: usize) -> Self;
+}
+
+macro_rules! impl_number_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn mean(self, count: usize) -> Self {
+                    // A count too large for the type is larger than any sum,
+                    // so the mean rounds to zero.
+                    Self::try_from(count).map_or(0, |count| self / count)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Whether a float operation went out of range: finite operands giving an
+/// infinite result, or operands other than NaN giving NaN, like `inf - inf`.
+fn float_out_of_range(lhs: f64, rhs: f64, result: f64) -> bool {
+    let overflowed = result.is_infinite() && lhs.is_finite() && rhs.is_finite();
+    let undefined = result.is_nan() && !lhs.is_nan() && !rhs.is_nan();
+    overflowed || undefined
+}
+
+macro_rules! impl_number_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    let sum = self + rhs;
+                    (!float_out_of_range(self.into(), rhs.into(), sum.into())).then_some(sum)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    let product = self * rhs;
+                    (!float_out_of_range(self.into(), rhs.into(), product.into()))
+                        .then_some(product)
+                }
+
+                fn mean(self, count: usize) -> Self {
+                    self / count as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_number_for_floats!(f32, f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Mean,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Op::Sum => "sum",
+            Op::Product => "product",
+            Op::Min => "min",
+            Op::Max => "max",
+            Op::Mean => "mean",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Why `aggregate` failed. `E` is the parse error of the input type, e.g.
+/// `ParseIntError`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AggregateError<E> {
+    #[error("input #{index} {input:?} is not a number: {source}")]
+    Parse {
+        index: usize,
+        input: String,
+        #[source]
+        source: E,
+    },
+    #[error("the {op} overflowed at input #{index} {input:?}")]
+    Overflow { index: usize, input: String, op: Op },
+    /// Min, max and mean have no value for zero inputs.
+    #[error("the {op} of no inputs is undefined")]
+    Empty { op: Op },
+}
+
+/// Folds values with `op`, checking for overflow.
+struct Accumulator<A> {
+    op: Op,
+    acc: Option<A>,
+    count: usize,
+}
+
+impl<A: Number> Accumulator<A> {
+    fn new(op: Op) -> Self {
+        Self {
+            op,
+            acc: None,
+            count: 0,
+        }
+    }
+
+    fn push<E>(&mut self, index: usize, input: &str, value: A) -> Result<(), AggregateError<E>> {
+        let overflow = || AggregateError::Overflow {
+            index,
+            input: input.to_string(),
+            op: self.op,
+        };
+
+        self.acc = Some(match self.acc {
+            None => value,
+            Some(acc) => match self.op {
+                Op::Sum | Op::Mean => acc.checked_add(value).ok_or_else(overflow)?,
+                Op::Product => acc.checked_mul(value).ok_or_else(overflow)?,
+                Op::Min if value < acc => value,
+                Op::Max if value > acc => value,
+                Op::Min | Op::Max => acc,
+            },
+        });
+        self.count += 1;
+        Ok(())
+    }
+
+    fn finish<E>(self) -> Result<A, AggregateError<E>> {
+        match (self.op, self.acc) {
+            (Op::Mean, Some(sum)) => Ok(sum.mean(self.count)),
+            (_, Some(acc)) => Ok(acc),
+            (Op::Sum, None) => Ok(A::ZERO),
+            (Op::Product, None) => Ok(A::ONE),
+            (op, None) => Err(AggregateError::Empty { op }),
+        }
+    }
+}
+
+fn parse<T: FromStr, A: From<T>>(input: &str) -> Result<A, T::Err> {
+    input.parse::<T>().map(A::from)
+}
+
+/**
+ * Parses each input as a `T`, converts it to `A` and folds them with `op`.
+ *
+ * The sum of no inputs is zero and their product one, while min, max and
+ * mean return `AggregateError::Empty`.
+ */
+pub fn aggregate<T, A>(
+    inputs: impl IntoIterator<Item = impl AsRef<str>>,
+    op: Op,
+) -> Result<A, AggregateError<T::Err>>
+where
+    T: FromStr,
+    A: Number + From<T>,
+{
+    aggregate_with(inputs, op, parse::<T, A>)
+}
+
+/// Like `aggregate`, parsing each input with `parse` instead of `FromStr`.
+pub fn aggregate_with<A: Number, E>(
+    inputs: impl IntoIterator<Item = impl AsRef<str>>,
+    op: Op,
+    parse: impl Fn(&str) -> Result<A, E>,
+) -> Result<A, AggregateError<E>> {
+    let mut acc = Accumulator::new(op);
+    for (index, input) in inputs.into_iter().enumerate() {
+        let input = input.as_ref();
+        let value = parse(input).map_err(|source| AggregateError::Parse {
+            index,
+            input: input.to_string(),
+            source,
+        })?;
+        acc.push(index, input, value)?;
+    }
+    acc.finish()
+}
+
+/**
+ * Every error `aggregate_all` ran into, and the aggregate of the inputs that
+ * did parse when there is one.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiError<A, E> {
+    /// Parse errors in input order, then the overflow or empty input that
+    /// stopped the aggregation, if any.
+    pub errors: Vec<AggregateError<E>>,
+    /// The aggregate of the valid inputs, `None` if it overflowed or is
+    /// undefined.
+    pub partial: Option<A>,
+}
+
+impl<A, E> MultiError<A, E> {
+    /// The inputs that did not parse, as `(index, input, error)`.
+    pub fn parse_errors(&self) -> impl Iterator<Item = (usize, &str, &E)> {
+        self.errors.iter().filter_map(|error| match error {
+            AggregateError::Parse {
+                index,
+                input,
+                source,
+            } => Some((*index, input.as_str(), source)),
+            _ => None,
+        })
+    }
+}
+
+impl<A, E: fmt::Display> fmt::Display for MultiError<A, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} error(s)", self.errors.len())?;
+        for error in &self.errors {
+            write!(f, "\n- {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<A: fmt::Debug, E: error::Error + 'static> error::Error for MultiError<A, E> {}
+
+/**
+ * Like `aggregate`, but instead of stopping at the first input that does not
+ * parse, skips it and goes on, collecting every error in a `MultiError`.
+ * Overflow still stops the aggregation, since no later input can undo it.
+ */
+pub fn aggregate_all<T, A>(
+    inputs: impl IntoIterator<Item = impl AsRef<str>>,
+    op: Op,
+) -> Result<A, MultiError<A, T::Err>>
+where
+    T: FromStr,
+    A: Number + From<T>,
+{
+    let mut acc = Some(Accumulator::new(op));
+    let mut errors = Vec::new();
+    let mut overflow = None;
+
+    for (index, input) in inputs.into_iter().enumerate() {
+        let input = input.as_ref();
+        match parse::<T, A>(input) {
+            Ok(value) => {
+                if let Some(a) = &mut acc {
+                    if let Err(e) = a.push(index, input, value) {
+                        overflow = Some(e);
+                        acc = None;
+                    }
+                }
+            }
+            Err(source) => errors.push(AggregateError::Parse {
+                index,
+                input: input.to_string(),
+                source,
+            }),
+        }
+    }
+
+    let partial = match acc.map(Accumulator::finish).transpose() {
+        Ok(partial) => partial,
+        Err(e) => {
+            errors.push(e);
+            None
+        }
+    };
+    errors.extend(overflow);
+
+    match partial {
+        Some(value) if errors.is_empty() => Ok(value),
+        partial => Err(MultiError { errors, partial }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::IntErrorKind;
+
+    #[test]
+    fn should_aggregate_with_every_op() {
+        let inputs = ["3", "-1", "4", "2"];
+
+        assert_eq!(aggregate::<i32, i32>(inputs, Op::Sum), Ok(8));
+        assert_eq!(aggregate::<i32, i32>(inputs, Op::Product), Ok(-24));
+        assert_eq!(aggregate::<i32, i32>(inputs, Op::Min), Ok(-1));
+        assert_eq!(aggregate::<i32, i32>(inputs, Op::Max), Ok(4));
+        assert_eq!(aggregate::<i32, i32>(inputs, Op::Mean), Ok(2));
+        assert_eq!(aggregate::<i32, f64>(inputs, Op::Mean), Ok(2.0));
+        assert_eq!(aggregate::<f32, f64>(["0.5", "1.25"], Op::Sum), Ok(1.75));
+    }
+
+    #[test]
+    fn should_accept_owned_and_borrowed_strings() {
+        let owned = vec!["1".to_string(), "2".to_string()];
+
+        assert_eq!(aggregate::<u8, u64>(&owned, Op::Sum), Ok(3));
+        assert_eq!(aggregate::<u8, u64>(owned, Op::Product), Ok(2));
+    }
+
+    #[test]
+    fn should_handle_empty_inputs() {
+        let none: [&str; 0] = [];
+
+        assert_eq!(aggregate::<i32, i32>(none, Op::Sum), Ok(0));
+        assert_eq!(aggregate::<i32, i32>(none, Op::Product), Ok(1));
+        for op in [Op::Min, Op::Max, Op::Mean] {
+            assert_eq!(
+                aggregate::<i32, i32>(none, op),
+                Err(AggregateError::Empty { op })
+            );
+        }
+    }
+
+    #[test]
+    fn should_report_index_and_text_of_bad_input() {
+        let error = aggregate::<i32, i32>(["1", "2abc", "x"], Op::Sum).unwrap_err();
+        let AggregateError::Parse {
+            index,
+            input,
+            source,
+        } = &error
+        else {
+            panic!("expected a parse error, got {error:?}");
+        };
+
+        assert_eq!((*index, input.as_str()), (1, "2abc"));
+        assert_eq!(source.kind(), &IntErrorKind::InvalidDigit);
+        assert_eq!(
+            error.to_string(),
+            "input #1 \"2abc\" is not a number: invalid digit found in string"
+        );
+        assert!(matches!(
+            aggregate::<f64, f64>(["1", "one"], Op::Sum),
+            Err(AggregateError::Parse { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn should_detect_overflow_as_its_own_error() {
+        let inputs = ["2147483647", "1"];
+
+        assert_eq!(
+            aggregate::<i32, i32>(inputs, Op::Sum),
+            Err(AggregateError::Overflow {
+                index: 1,
+                input: "1".to_string(),
+                op: Op::Sum
+            })
+        );
+        assert_eq!(aggregate::<i32, i64>(inputs, Op::Sum), Ok(2147483648));
+        assert_eq!(
+            aggregate::<u8, u8>(["16", "16"], Op::Product)
+                .unwrap_err()
+                .to_string(),
+            "the product overflowed at input #1 \"16\""
+        );
+        assert!(matches!(
+            aggregate::<f64, f64>(["1e308", "1e308"], Op::Mean),
+            Err(AggregateError::Overflow { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn should_collect_every_parse_error_with_a_partial_result() {
+        let inputs = ["1", "", "2abc", "3", "99999999999"];
+
+        let error = aggregate_all::<i32, i32>(inputs, Op::Sum).unwrap_err();
+        let kinds: Vec<_> = error
+            .parse_errors()
+            .map(|(index, input, e)| (index, input, *e.kind()))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            [
+                (1, "", IntErrorKind::Empty),
+                (2, "2abc", IntErrorKind::InvalidDigit),
+                (4, "99999999999", IntErrorKind::PosOverflow),
+            ]
+        );
+        assert_eq!(error.partial, Some(4));
+        assert_eq!(
+            error.to_string(),
+            "3 error(s)\n\
+             - input #1 \"\" is not a number: cannot parse integer from empty string\n\
+             - input #2 \"2abc\" is not a number: invalid digit found in string\n\
+             - input #4 \"99999999999\" is not a number: number too large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn should_compare_fail_fast_with_accumulation() {
+        let inputs = ["1", "x", "2", "y"];
+
+        // `?` stops at the first bad input and has nothing else to report...
+        let fail_fast = aggregate::<i32, i32>(inputs, Op::Max).unwrap_err();
+        assert!(matches!(fail_fast, AggregateError::Parse { index: 1, .. }));
+
+        // ...while accumulating reports both, along with the max of the rest.
+        let accumulated = aggregate_all::<i32, i32>(inputs, Op::Max).unwrap_err();
+        let indices: Vec<_> = accumulated.parse_errors().map(|(i, _, _)| i).collect();
+        assert_eq!(indices, [1, 3]);
+        assert_eq!(accumulated.partial, Some(2));
+
+        // Both agree when every input is valid.
+        assert_eq!(aggregate_all::<i32, i32>(["1", "2"], Op::Max), Ok(2));
+        assert_eq!(aggregate::<i32, i32>(["1", "2"], Op::Max), Ok(2));
+    }
+
+    #[test]
+    fn should_report_overflow_and_empty_input_after_parse_errors() {
+        let error = aggregate_all::<u8, u8>(["200", "oops", "100", "bad"], Op::Sum).unwrap_err();
+        assert_eq!(error.partial, None);
+        assert_eq!(error.parse_errors().count(), 2);
+        assert!(matches!(
+            error.errors.last(),
+            Some(AggregateError::Overflow { index: 2, .. })
+        ));
+
+        let error = aggregate_all::<i32, i32>(["a", "b"], Op::Mean).unwrap_err();
+        assert_eq!(error.partial, None);
+        assert_eq!(
+            error.errors.last(),
+            Some(&AggregateError::Empty { op: Op::Mean })
+        );
+
+        let error = aggregate_all::<i32, i32>(["a", "b"], Op::Sum).unwrap_err();
+        assert_eq!((error.errors.len(), error.partial), (2, Some(0)));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::game::MyError;
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+pub const USAGE: &str = "\
+Usage: cargo run -p m4_error_handling --bin guessing_game -- [options]
+
+Options:
+  --difficulty <easy|normal|hard>   Start from a preset (default: easy)
+  --min <n>                         Smallest possible secret number
+  --max <n>                         Largest possible secret number
+  --attempts <n>                    Guesses allowed before losing, 0 for unlimited
+  --history-file <path>             Where finished games are recorded
+                                    (default: .guessing_game_history.json)
+  --stats                           Show best scores and leaderboards instead of playing
+  --history                         List all recorded games instead of playing
+  --simulate <n>                    Let every computer strategy play n games
+  --seed <n>                        Seed of the simulated games (default: random)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn config(self) -> Config {
+        match self {
+            Difficulty::Easy => Config {
+                min: 1,
+                max: 10,
+                max_attempts: None,
+            },
+            Difficulty::Normal => Config {
+                min: 1,
+                max: 100,
+                max_attempts: Some(7),
+            },
+            Difficulty::Hard => Config {
+                min: 1,
+                max: 1000,
+                max_attempts: Some(10),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = MyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(MyError::ConfigError(format!(
+                "Unknown difficulty {s:?}, expected easy, normal or hard."
+            ))),
+        }
+    }
+}
+
+/// The rules of a game: where the secret number comes from and how many
+/// guesses the player has.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub min: u32,
+    pub max: u32,
+    /// `None` lets the player guess until they win.
+    pub max_attempts: Option<u32>,
+}
+
+/// The original game: 1 to 10, guessing until the player wins.
+impl Default for Config {
+    fn default() -> Self {
+        Difficulty::Easy.config()
+    }
+}
+
+impl Config {
+    pub fn new(min: u32, max: u32, max_attempts: Option<u32>) -> Result<Self, MyError> {
+        if min > max {
+            return Err(MyError::ConfigError(format!(
+                "The range {min}..={max} is empty."
+            )));
+        }
+        Ok(Config {
+            min,
+            max,
+            max_attempts,
+        })
+    }
+
+    /// The preset these rules match, if any.
+    pub fn difficulty(&self) -> Option<Difficulty> {
+        [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard]
+            .into_iter()
+            .find(|d| d.config() == *self)
+    }
+
+    pub fn range(&self) -> RangeInclusive<u32> {
+        self.min..=self.max
+    }
+
+    /**
+     * Builds a configuration from command-line flags. `--difficulty` picks the
+     * preset the other flags start from, wherever it appears.
+     */
+    pub fn from_args<I>(args: I) -> Result<Self, MyError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let args: Vec<String> = args.into_iter().collect();
+        let mut difficulty = Difficulty::Easy;
+        let mut min = None;
+        let mut max = None;
+        let mut attempts = None;
+
+        let mut args = args.iter();
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| MyError::ConfigError(format!("Missing value for {flag}.")))?;
+
+            match flag.as_str() {
+                "--difficulty" => difficulty = value.parse()?,
+                "--min" => min = Some(parse_flag(flag, value)?),
+                "--max" => max = Some(parse_flag(flag, value)?),
+                "--attempts" => attempts = Some(parse_flag(flag, value)?),
+                _ => return Err(MyError::ConfigError(format!("Unknown option {flag}."))),
+            }
+        }
+
+        let preset = difficulty.config();
+        let max_attempts = match attempts {
+            Some(0) => None,
+            Some(n) => Some(n),
+            None => preset.max_attempts,
+        };
+        Config::new(
+            min.unwrap_or(preset.min),
+            max.unwrap_or(preset.max),
+            max_attempts,
+        )
+    }
+}
+
+fn parse_flag(flag: &str, value: &str) -> Result<u32, MyError> {
+    value.parse().map_err(|_| {
+        MyError::ConfigError(format!("{flag} expects a positive number, got {value:?}."))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn should_default_to_the_original_game() {
+        let config = Config::from_args(args("")).unwrap();
+
+        assert_eq!(config, Config::default());
+        assert_eq!((config.range(), config.max_attempts), (1..=10, None));
+    }
+
+    #[test]
+    fn should_override_preset_with_flags() {
+        let config = Config::from_args(args("--max 50 --difficulty hard --attempts 0")).unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                min: 1,
+                max: 50,
+                max_attempts: None,
+            }
+        );
+    }
+
+    #[test]
+    fn should_reject_bad_flags() {
+        for line in [
+            "--difficulty insane",
+            "--min -1",
+            "--max",
+            "--min 10 --max 1",
+            "--colour red",
+        ] {
+            assert!(
+                matches!(Config::from_args(args(line)), Err(MyError::ConfigError(_))),
+                "{line}"
+            );
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use crate::config::Config;
+use rand::Rng;
+use std::{
+    cmp::Ordering,
+    error, fmt,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+
+#[derive(Debug)]
+pub enum MyError {
+    IoError(io::Error),
+    ParseError(String),
+    RangeError(String),
+    ConfigError(String),
+    HistoryError { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MyError::IoError(e) => write!(f, "Failed to read line: {e}"),
+            MyError::ParseError(msg) => write!(f, "{msg}"),
+            MyError::RangeError(msg) => write!(f, "{msg}"),
+            MyError::ConfigError(msg) => write!(f, "{msg}"),
+            MyError::HistoryError { path, source } => {
+                write!(f, "Cannot access game history {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl error::Error for MyError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            MyError::IoError(e) | MyError::HistoryError { source: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MyError {
+    fn from(e: io::Error) -> Self {
+        MyError::IoError(e)
+    }
+}
+
+/// What happened in a finished game.
+#[derive(Debug, PartialEq)]
+pub struct GameSummary {
+    pub secret_number: u32,
+    pub guesses: Vec<u32>,
+    pub won: bool,
+}
+
+impl GameSummary {
+    pub fn attempts(&self) -> usize {
+        self.guesses.len()
+    }
+}
+
+/// The rules and state of one game, independent of who is guessing.
+pub struct Game {
+    config: Config,
+    secret_number: u32,
+    guesses: Vec<u32>,
+}
+
+impl Game {
+    pub fn new<G: Rng>(config: &Config, rng: &mut G) -> Self {
+        Game {
+            config: config.clone(),
+            secret_number: rng.gen_range(config.range()),
+            guesses: Vec::new(),
+        }
+    }
+
+    /**
+     * Takes a guess and tells how it compares to the secret number. Guesses
+     * outside of the configured range are rejected and do not count.
+     */
+    pub fn guess(&mut self, guess: u32) -> Result<Ordering, MyError> {
+        if !self.config.range().contains(&guess) {
+            return Err(MyError::RangeError(format!(
+                "The secret number will be between {} and {}, got {guess}.",
+                self.config.min, self.config.max
+            )));
+        }
+
+        self.guesses.push(guess);
+        Ok(guess.cmp(&self.secret_number))
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.guesses.last() == Some(&self.secret_number)
+    }
+
+    /// `None` when the number of guesses is unlimited.
+    pub fn attempts_left(&self) -> Option<u32> {
+        self.config
+            .max_attempts
+            .map(|max| max.saturating_sub(self.guesses.len() as u32))
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.is_won() || self.attempts_left() == Some(0)
+    }
+
+    pub fn summary(self) -> GameSummary {
+        GameSummary {
+            won: self.is_won(),
+            secret_number: self.secret_number,
+            guesses: self.guesses,
+        }
+    }
+}
+
+/**
+ * Plays one game with the rules of `config`, reading guesses from `input` and
+ * writing messages to `output`. The secret number is drawn from `rng`, so a
+ * seeded RNG makes the game reproducible.
+ */
+pub fn play<R, W, G>(
+    config: &Config,
+    input: &mut R,
+    output: &mut W,
+    rng: &mut G,
+) -> Result<GameSummary, MyError>
+where
+    R: BufRead,
+    W: Write,
+    G: Rng,
+{
+    writeln!(output, "Guess the number! ({}-{})", config.min, config.max)?;
+
+    let mut game = Game::new(config, rng);
+
+    while !game.is_over() {
+        match game.attempts_left() {
+            Some(left) => writeln!(output, "Please input your guess ({left} left).")?,
+            None => writeln!(output, "Please input your guess.")?,
+        }
+
+        let mut guess = String::new();
+        if input.read_line(&mut guess)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "no more input").into());
+        }
+
+        let guess: u32 = guess.trim().parse().map_err(|_| {
+            MyError::ParseError(format!("Guess value must be a number: {:?}", guess.trim()))
+        })?;
+
+        let ordering = game.guess(guess)?;
+        writeln!(output, "You guessed: {guess}")?;
+
+        match ordering {
+            Ordering::Less => writeln!(output, "Too small!")?,
+            Ordering::Greater => writeln!(output, "Too big!")?,
+            Ordering::Equal => writeln!(output, "You win!")?,
+        }
+    }
+
+    let summary = game.summary();
+    if !summary.won {
+        writeln!(
+            output,
+            "Out of guesses! The number was {}.",
+            summary.secret_number
+        )?;
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Difficulty;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// A reader that always fails, like a closed or broken stdin.
+    struct BrokenReader;
+
+    impl io::Read for BrokenReader {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "stdin is gone"))
+        }
+    }
+
+    impl BufRead for BrokenReader {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "stdin is gone"))
+        }
+
+        fn consume(&mut self, _: usize) {}
+    }
+
+    fn play_with(config: &Config, input: &str) -> (Result<GameSummary, MyError>, String) {
+        let mut output = Vec::new();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = play(config, &mut input.as_bytes(), &mut output, &mut rng);
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn should_return_summary_when_guessed_correctly() {
+        let secret_number = StdRng::seed_from_u64(42).gen_range(1..=10);
+        let input: String = (1..=10).map(|n| format!("{n}\n")).collect();
+
+        let (result, output) = play_with(&Difficulty::Easy.config(), &input);
+
+        let expected = GameSummary {
+            secret_number,
+            guesses: (1..=secret_number).collect(),
+            won: true,
+        };
+        assert_eq!(result.unwrap(), expected);
+        assert!(output.ends_with("You win!\n"));
+    }
+
+    #[test]
+    fn should_return_io_error_when_fails_to_read() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = play(
+            &Config::default(),
+            &mut BrokenReader,
+            &mut io::sink(),
+            &mut rng,
+        );
+
+        assert!(
+            matches!(result, Err(MyError::IoError(e)) if e.kind() == io::ErrorKind::BrokenPipe)
+        );
+    }
+
+    #[test]
+    fn should_return_io_error_when_input_ends_before_win() {
+        let (result, _) = play_with(&Config::default(), "");
+
+        assert!(
+            matches!(result, Err(MyError::IoError(e)) if e.kind() == io::ErrorKind::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn should_return_parse_error_if_input_is_non_numeric() {
+        let (result, _) = play_with(&Config::default(), "five\n");
+
+        assert!(matches!(result, Err(MyError::ParseError(_))));
+    }
+
+    #[test]
+    fn should_retun_out_of_range_error_if_input_is_not_in_range() {
+        let (result, _) = play_with(&Difficulty::Easy.config(), "11\n");
+
+        assert!(matches!(result, Err(MyError::RangeError(_))));
+    }
+
+    #[test]
+    fn should_check_range_of_configured_game() {
+        let config = Config::new(50, 60, None).unwrap();
+
+        let (result, _) = play_with(&config, "49\n");
+        assert!(matches!(result, Err(MyError::RangeError(_))));
+
+        let (result, output) = play_with(&config, "55\n50\n51\n52\n53\n54\n56\n57\n58\n59\n60\n");
+        assert!(result.unwrap().won);
+        assert!(output.starts_with("Guess the number! (50-60)"));
+    }
+
+    #[test]
+    fn should_lose_when_attempts_run_out() {
+        let config = Config::new(1, 1000, Some(2)).unwrap();
+        let secret_number = StdRng::seed_from_u64(42).gen_range(config.range());
+        let wrong = if secret_number == 1 { 2 } else { 1 };
+
+        let (result, output) = play_with(&config, &format!("{wrong}\n{wrong}\n{secret_number}\n"));
+
+        let summary = result.unwrap();
+        assert!(!summary.won);
+        assert_eq!(summary.attempts(), 2);
+        assert!(output.ends_with(&format!(
+            "Out of guesses! The number was {secret_number}.\n"
+        )));
+    }
+}
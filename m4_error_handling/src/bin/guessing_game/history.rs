@@ -0,0 +1,224 @@
+use crate::game::{GameSummary, MyError};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+pub const DEFAULT_PATH: &str = ".guessing_game_history.json";
+
+/// How many games the leaderboard shows per difficulty.
+const LEADERBOARD_SIZE: usize = 5;
+
+/// One finished game, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub secret_number: u32,
+    pub guesses: Vec<u32>,
+    pub attempts: usize,
+    pub won: bool,
+    pub duration_ms: u64,
+    pub difficulty: String,
+}
+
+impl GameRecord {
+    pub fn new(summary: &GameSummary, duration: Duration, difficulty: &str) -> Self {
+        GameRecord {
+            secret_number: summary.secret_number,
+            guesses: summary.guesses.clone(),
+            attempts: summary.attempts(),
+            won: summary.won,
+            duration_ms: duration.as_millis() as u64,
+            difficulty: difficulty.to_string(),
+        }
+    }
+}
+
+/// All games played so far, oldest first, kept in a JSON file.
+pub struct History {
+    path: PathBuf,
+    pub games: Vec<GameRecord>,
+}
+
+impl History {
+    /// Loads the history at `path`; a missing file is an empty history.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, MyError> {
+        let path = path.into();
+        let games = match fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|e| history_error(&path, e.into()))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(history_error(&path, e)),
+        };
+        Ok(History { path, games })
+    }
+
+    pub fn save(&self) -> Result<(), MyError> {
+        let json = serde_json::to_string_pretty(&self.games)
+            .map_err(|e| history_error(&self.path, e.into()))?;
+        fs::write(&self.path, json).map_err(|e| history_error(&self.path, e))
+    }
+
+    pub fn record(&mut self, game: GameRecord) -> Result<(), MyError> {
+        self.games.push(game);
+        self.save()
+    }
+
+    /// Lists every game in the order it was played.
+    pub fn print_history(&self, output: &mut impl Write) -> io::Result<()> {
+        if self.games.is_empty() {
+            return writeln!(output, "No games played yet.");
+        }
+
+        for (i, game) in self.games.iter().enumerate() {
+            writeln!(
+                output,
+                "#{:<4}{:<8}{:<5}secret {:>4} in {:>2} attempts {:>7.1}s  {:?}",
+                i + 1,
+                game.difficulty,
+                if game.won { "won" } else { "lost" },
+                game.secret_number,
+                game.attempts,
+                game.duration_ms as f64 / 1000.0,
+                game.guesses
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Prints the win rate, average attempts and a leaderboard per difficulty.
+    pub fn print_stats(&self, output: &mut impl Write) -> io::Result<()> {
+        let won: Vec<&GameRecord> = self.games.iter().filter(|g| g.won).collect();
+
+        writeln!(
+            output,
+            "Games played: {}, won: {}",
+            self.games.len(),
+            won.len()
+        )?;
+        if let Some(average) = average_attempts(&won) {
+            writeln!(output, "Average attempts to win: {average:.2}")?;
+        }
+        if let Some(best) = won.iter().min_by_key(|g| (g.attempts, g.duration_ms)) {
+            writeln!(
+                output,
+                "Best score: {} attempts ({})",
+                best.attempts, best.difficulty
+            )?;
+        }
+
+        for (difficulty, games) in leaderboards(&won) {
+            writeln!(output, "{}", "-".repeat(50))?;
+            writeln!(output, "{difficulty}")?;
+            writeln!(output, "{}", "-".repeat(50))?;
+            for (rank, game) in games.iter().enumerate() {
+                writeln!(
+                    output,
+                    "  {}. {:>2} attempts {:>7.1}s",
+                    rank + 1,
+                    game.attempts,
+                    game.duration_ms as f64 / 1000.0
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn history_error(path: &Path, source: io::Error) -> MyError {
+    MyError::HistoryError {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+fn average_attempts(games: &[&GameRecord]) -> Option<f64> {
+    if games.is_empty() {
+        return None;
+    }
+    let total: usize = games.iter().map(|g| g.attempts).sum();
+    Some(total as f64 / games.len() as f64)
+}
+
+/// The best won games of each difficulty: fewest attempts first, then fastest.
+fn leaderboards<'a>(won: &[&'a GameRecord]) -> BTreeMap<&'a str, Vec<&'a GameRecord>> {
+    let mut boards: BTreeMap<&str, Vec<&GameRecord>> = BTreeMap::new();
+    for game in won {
+        boards.entry(&game.difficulty).or_default().push(game);
+    }
+    for games in boards.values_mut() {
+        games.sort_by_key(|g| (g.attempts, g.duration_ms));
+        games.truncate(LEADERBOARD_SIZE);
+    }
+    boards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(difficulty: &str, attempts: usize, won: bool, duration_ms: u64) -> GameRecord {
+        GameRecord {
+            secret_number: 7,
+            guesses: vec![7; attempts],
+            attempts,
+            won,
+            duration_ms,
+            difficulty: difficulty.to_string(),
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("guessing-game-{name}-{}.json", std::process::id()))
+    }
+
+    #[test]
+    fn should_rank_won_games_per_difficulty() {
+        let games = [
+            game("easy", 4, true, 900),
+            game("easy", 2, true, 3000),
+            game("easy", 2, true, 1000),
+            game("easy", 1, false, 10),
+            game("hard", 9, true, 20000),
+        ];
+        let won: Vec<&GameRecord> = games.iter().filter(|g| g.won).collect();
+
+        let boards = leaderboards(&won);
+
+        let easy: Vec<(usize, u64)> = boards["easy"]
+            .iter()
+            .map(|g| (g.attempts, g.duration_ms))
+            .collect();
+        assert_eq!(easy, [(2, 1000), (2, 3000), (4, 900)]);
+        assert_eq!(boards["hard"].len(), 1);
+        assert_eq!(average_attempts(&won), Some(4.25));
+    }
+
+    #[test]
+    fn should_round_trip_through_file() {
+        let path = temp_path("round-trip");
+        let _ = fs::remove_file(&path);
+
+        let mut history = History::load(&path).unwrap();
+        assert!(history.games.is_empty());
+        history.record(game("normal", 5, true, 1234)).unwrap();
+
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.games, [game("normal", 5, true, 1234)]);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn should_report_corrupt_file_as_history_error() {
+        let path = temp_path("corrupt");
+        fs::write(&path, "not json").unwrap();
+
+        let result = History::load(&path);
+
+        assert!(matches!(result, Err(MyError::HistoryError { path: p, .. }) if p == path));
+        fs::remove_file(&path).unwrap();
+    }
+}
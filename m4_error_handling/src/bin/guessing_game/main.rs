@@ -0,0 +1,115 @@
+mod config;
+mod history;
+mod strategy;
+
+/// Plays one game, reporting every failure as a `MyError`.
+mod game;
+
+use config::Config;
+use game::MyError;
+use history::{GameRecord, History};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{io, process::ExitCode, time::Instant};
+use strategy::{BinarySearch, Linear, Random, Strategy};
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e @ MyError::ConfigError(_)) => {
+            eprintln!("Error: {e}");
+            eprintln!("{}", config::USAGE);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Plays a game, or shows the recorded ones, and tells whether a game was won.
+fn run(mut args: Vec<String>) -> Result<bool, MyError> {
+    let history_path = take_option(&mut args, "--history-file")?
+        .unwrap_or_else(|| history::DEFAULT_PATH.to_string());
+    let simulate = take_option(&mut args, "--simulate")?;
+    let seed = take_option(&mut args, "--seed")?;
+
+    if let Some(games) = simulate {
+        let games = parse_number(&games, "--simulate")?;
+        let seed: u64 = match seed {
+            Some(seed) => parse_number(&seed, "--seed")?,
+            None => rand::random(),
+        };
+        let config = Config::from_args(args)?;
+
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(BinarySearch::default()),
+            // Offset so the bot does not draw the same numbers as the engine
+            Box::new(Random::new(seed.wrapping_add(1))),
+            Box::new(Linear::default()),
+        ];
+        let simulations = strategy::simulate(&config, &mut strategies, games, seed)?;
+
+        println!("Simulated {games} games per strategy (seed {seed})");
+        strategy::print_simulations(&simulations, &mut io::stdout())?;
+        return Ok(true);
+    }
+
+    if let [mode] = &args[..] {
+        match mode.as_str() {
+            "--stats" => {
+                History::load(history_path)?.print_stats(&mut io::stdout())?;
+                return Ok(true);
+            }
+            "--history" => {
+                History::load(history_path)?.print_history(&mut io::stdout())?;
+                return Ok(true);
+            }
+            _ => {}
+        }
+    }
+
+    let config = Config::from_args(args)?;
+    let mut history = History::load(history_path)?;
+    let mut rng = StdRng::from_entropy();
+
+    let started = Instant::now();
+    let summary = game::play(
+        &config,
+        &mut io::stdin().lock(),
+        &mut io::stdout(),
+        &mut rng,
+    )?;
+    let difficulty = config
+        .difficulty()
+        .map_or_else(|| "custom".to_string(), |d| d.to_string());
+    history.record(GameRecord::new(&summary, started.elapsed(), &difficulty))?;
+
+    if summary.won {
+        println!(
+            "Found {} in {} attempts.",
+            summary.secret_number,
+            summary.attempts()
+        );
+    }
+    Ok(summary.won)
+}
+
+/// Removes `flag` and its value from `args`.
+fn take_option(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, MyError> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(i) if i + 1 < args.len() => {
+            args.remove(i);
+            Ok(Some(args.remove(i)))
+        }
+        Some(_) => Err(MyError::ConfigError(format!("Missing value for {flag}."))),
+        None => Ok(None),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, flag: &str) -> Result<T, MyError> {
+    value.parse().map_err(|_| {
+        MyError::ConfigError(format!("{flag} expects a positive number, got {value:?}."))
+    })
+}
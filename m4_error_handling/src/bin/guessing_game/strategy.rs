@@ -0,0 +1,307 @@
+use crate::{
+    config::Config,
+    game::{Game, GameSummary, MyError},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    io::{self, Write},
+};
+
+/// A computer player: proposes guesses and learns from the engine's feedback.
+pub trait Strategy {
+    fn name(&self) -> &str;
+
+    /// Prepares for a new game played with the rules of `config`.
+    fn start(&mut self, config: &Config);
+
+    fn next_guess(&mut self) -> u32;
+
+    /// `ordering` compares the last guess to the secret number.
+    fn feedback(&mut self, guess: u32, ordering: Ordering);
+}
+
+/// Halves the remaining range with every guess.
+#[derive(Default)]
+pub struct BinarySearch {
+    low: u32,
+    high: u32,
+}
+
+impl Strategy for BinarySearch {
+    fn name(&self) -> &str {
+        "binary"
+    }
+
+    fn start(&mut self, config: &Config) {
+        self.low = config.min;
+        self.high = config.max;
+    }
+
+    fn next_guess(&mut self) -> u32 {
+        self.low + (self.high - self.low) / 2
+    }
+
+    fn feedback(&mut self, guess: u32, ordering: Ordering) {
+        match ordering {
+            Ordering::Less => self.low = guess + 1,
+            Ordering::Greater => self.high = guess - 1,
+            Ordering::Equal => {}
+        }
+    }
+}
+
+/// Picks any number that is still possible.
+pub struct Random {
+    rng: StdRng,
+    low: u32,
+    high: u32,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random {
+            rng: StdRng::seed_from_u64(seed),
+            low: 0,
+            high: 0,
+        }
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn start(&mut self, config: &Config) {
+        self.low = config.min;
+        self.high = config.max;
+    }
+
+    fn next_guess(&mut self) -> u32 {
+        self.rng.gen_range(self.low..=self.high)
+    }
+
+    fn feedback(&mut self, guess: u32, ordering: Ordering) {
+        match ordering {
+            Ordering::Less => self.low = guess + 1,
+            Ordering::Greater => self.high = guess - 1,
+            Ordering::Equal => {}
+        }
+    }
+}
+
+/// Counts up from the smallest number, ignoring whether it was too small.
+#[derive(Default)]
+pub struct Linear {
+    next: u32,
+}
+
+impl Strategy for Linear {
+    fn name(&self) -> &str {
+        "linear"
+    }
+
+    fn start(&mut self, config: &Config) {
+        self.next = config.min;
+    }
+
+    fn next_guess(&mut self) -> u32 {
+        self.next
+    }
+
+    fn feedback(&mut self, guess: u32, _: Ordering) {
+        self.next = guess + 1;
+    }
+}
+
+/// Lets `strategy` play one game against the engine.
+pub fn play<G: Rng>(
+    config: &Config,
+    strategy: &mut dyn Strategy,
+    rng: &mut G,
+) -> Result<GameSummary, MyError> {
+    let mut game = Game::new(config, rng);
+    strategy.start(config);
+
+    while !game.is_over() {
+        let guess = strategy.next_guess();
+        let ordering = game.guess(guess)?;
+        strategy.feedback(guess, ordering);
+    }
+    Ok(game.summary())
+}
+
+/// How a strategy did over many games.
+#[derive(Debug, PartialEq)]
+pub struct Simulation {
+    pub name: String,
+    pub games: usize,
+    pub lost: usize,
+    /// Number of won games by the attempts they took.
+    pub attempts: BTreeMap<usize, usize>,
+}
+
+impl Simulation {
+    pub fn mean_attempts(&self) -> Option<f64> {
+        let won: usize = self.attempts.values().sum();
+        let total: usize = self.attempts.iter().map(|(a, n)| a * n).sum();
+        (won > 0).then(|| total as f64 / won as f64)
+    }
+}
+
+/**
+ * Plays `games` games with every strategy. All strategies face the same
+ * sequence of secret numbers, drawn from an RNG seeded with `seed`.
+ */
+pub fn simulate(
+    config: &Config,
+    strategies: &mut [Box<dyn Strategy>],
+    games: usize,
+    seed: u64,
+) -> Result<Vec<Simulation>, MyError> {
+    let mut simulations = Vec::new();
+
+    for strategy in strategies {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut simulation = Simulation {
+            name: strategy.name().to_string(),
+            games,
+            lost: 0,
+            attempts: BTreeMap::new(),
+        };
+
+        for _ in 0..games {
+            let summary = play(config, strategy.as_mut(), &mut rng)?;
+            if summary.won {
+                *simulation.attempts.entry(summary.attempts()).or_default() += 1;
+            } else {
+                simulation.lost += 1;
+            }
+        }
+        simulations.push(simulation);
+    }
+    Ok(simulations)
+}
+
+/// Prints the distribution of attempts of every simulated strategy.
+pub fn print_simulations(simulations: &[Simulation], output: &mut impl Write) -> io::Result<()> {
+    const BAR_WIDTH: usize = 40;
+
+    for simulation in simulations {
+        writeln!(output, "{}", "-".repeat(50))?;
+        write!(
+            output,
+            "{}: {} games, {} lost",
+            simulation.name, simulation.games, simulation.lost
+        )?;
+        match simulation.mean_attempts() {
+            Some(mean) => writeln!(output, ", {mean:.2} attempts on average")?,
+            None => writeln!(output)?,
+        }
+        writeln!(output, "{}", "-".repeat(50))?;
+
+        let most = simulation.attempts.values().max().copied().unwrap_or(1);
+        for (attempts, count) in &simulation.attempts {
+            let bar = "#".repeat((count * BAR_WIDTH).div_ceil(most));
+            writeln!(output, "  {attempts:>4} | {bar} {count}")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Difficulty;
+
+    fn strategies() -> Vec<Box<dyn Strategy>> {
+        vec![
+            Box::new(BinarySearch::default()),
+            Box::new(Random::new(7)),
+            Box::new(Linear::default()),
+        ]
+    }
+
+    #[test]
+    fn binary_search_should_win_within_log2_attempts() {
+        let config = Config::new(1, 100, None).unwrap();
+
+        let simulations = simulate(&config, &mut strategies()[..1], 1000, 42).unwrap();
+
+        let binary = &simulations[0];
+        assert_eq!(binary.lost, 0);
+        assert!(binary.attempts.keys().all(|&attempts| attempts <= 7));
+    }
+
+    #[test]
+    fn binary_search_should_win_every_preset_whatever_the_secret_number() {
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            let config = difficulty.config();
+            let mut binary = BinarySearch::default();
+
+            for secret_number in config.range() {
+                binary.start(&config);
+                let mut attempts = 1;
+                loop {
+                    let guess = binary.next_guess();
+                    let ordering = guess.cmp(&secret_number);
+                    if ordering == Ordering::Equal {
+                        break;
+                    }
+                    binary.feedback(guess, ordering);
+                    attempts += 1;
+                }
+
+                assert!(
+                    config.max_attempts.is_none_or(|max| attempts <= max),
+                    "{difficulty}: {secret_number} took {attempts} attempts"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn linear_should_need_as_many_attempts_as_the_secret_number() {
+        let config = Config::new(1, 10, None).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let summary = play(&config, &mut Linear::default(), &mut rng).unwrap();
+
+        assert!(summary.won);
+        assert_eq!(summary.attempts(), summary.secret_number as usize);
+    }
+
+    #[test]
+    fn every_strategy_should_stay_in_range_and_eventually_win() {
+        let config = Difficulty::Easy.config();
+
+        for simulation in simulate(&config, &mut strategies(), 500, 1).unwrap() {
+            assert_eq!(simulation.lost, 0, "{}", simulation.name);
+            assert_eq!(simulation.attempts.values().sum::<usize>(), 500);
+        }
+    }
+
+    #[test]
+    fn random_should_need_more_attempts_than_binary_search_on_average() {
+        let config = Config::new(1, 100, None).unwrap();
+        let mut strategies = strategies();
+
+        let simulations = simulate(&config, &mut strategies[..2], 1000, 3).unwrap();
+
+        let binary = simulations[0].mean_attempts().unwrap();
+        let random = simulations[1].mean_attempts().unwrap();
+        assert!(binary < random, "binary {binary}, random {random}");
+    }
+
+    #[test]
+    fn simulation_should_be_reproducible_with_the_same_seed() {
+        let config = Difficulty::Normal.config();
+
+        let first = simulate(&config, &mut strategies(), 200, 9).unwrap();
+        let second = simulate(&config, &mut strategies(), 200, 9).unwrap();
+
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,105 @@
+//! Adds up the numbers in files, or on stdin, without reading them all first.
+//!
+//! ```text
+//! seq 1 100 | cargo run -p m4_error_handling --bin sum
+//! cargo run -p m4_error_handling --bin sum -- --type decimal prices.txt
+//! ```
+
+use m4_error_handling::{
+    numeric::{Decimal, Numeric},
+    streaming::{Sum, SumError},
+};
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    process::ExitCode,
+};
+use thiserror::Error;
+
+const USAGE: &str = "\
+Usage: cargo run -p m4_error_handling --bin sum -- [options] [FILE...]
+
+Reads numbers separated by newlines or commas from each FILE, or from stdin
+when there is none or FILE is -, and prints their sum.
+
+Options:
+  --type <i64|u128|f64|decimal>   How to read the numbers (default: i64)";
+
+#[derive(Error, Debug)]
+enum Error {
+    #[error("{0}")]
+    Usage(String),
+    #[error("cannot open {name}: {source}")]
+    Open {
+        name: String,
+        #[source]
+        source: io::Error,
+    },
+    #[error(transparent)]
+    Sum(#[from] SumError),
+}
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(total) => {
+            println!("{total}");
+            ExitCode::SUCCESS
+        }
+        Err(e @ Error::Usage(_)) => {
+            eprintln!("Error: {e}");
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Sums the inputs named in `args` and returns the total, formatted.
+fn run(mut args: Vec<String>) -> Result<String, Error> {
+    let kind = take_option(&mut args, "--type")?;
+    if let Some(arg) = args.iter().find(|arg| arg.starts_with("--")) {
+        return Err(Error::Usage(format!("Unknown option {arg}.")));
+    }
+
+    match kind.as_deref().unwrap_or("i64") {
+        "i64" => sum::<i64>(&args),
+        "u128" => sum::<u128>(&args),
+        "f64" => sum::<f64>(&args),
+        "decimal" => sum::<Decimal>(&args),
+        other => Err(Error::Usage(format!("Unknown number type {other:?}."))),
+    }
+}
+
+fn sum<N: Numeric>(files: &[String]) -> Result<String, Error> {
+    let mut sum = Sum::<N>::new();
+    if files.is_empty() {
+        sum.add_reader("<stdin>", io::stdin().lock())?;
+    }
+    for name in files {
+        if name == "-" {
+            sum.add_reader("<stdin>", io::stdin().lock())?;
+        } else {
+            let file = File::open(name).map_err(|source| Error::Open {
+                name: name.clone(),
+                source,
+            })?;
+            sum.add_reader(name, BufReader::new(file))?;
+        }
+    }
+    Ok(sum.total().to_string())
+}
+
+/// Removes `flag` and its value from `args`.
+fn take_option(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, Error> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(i) if i + 1 < args.len() => {
+            args.remove(i);
+            Ok(Some(args.remove(i)))
+        }
+        Some(_) => Err(Error::Usage(format!("Missing value for {flag}."))),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,151 @@
+//! Saying what was being done when an error happened, without `anyhow`.
+//!
+//! `File::open` fails with "No such file or directory", but not which file or
+//! why it was opened. `.context("reading hello.txt")` wraps the error in a
+//! `ContextError` that says so, and keeps the original as its `source`.
+
+use std::{convert::Infallible, error::Error, fmt};
+
+/// An error, or a missing value, with a message about what was being done.
+#[derive(Debug)]
+pub struct ContextError<E = Infallible> {
+    context: String,
+    source: Option<E>,
+}
+
+impl<E> ContextError<E> {
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+
+    /// The wrapped error, `None` for a missing `Option` value.
+    pub fn inner(&self) -> Option<&E> {
+        self.source.as_ref()
+    }
+
+    pub fn into_inner(self) -> Option<E> {
+        self.source
+    }
+}
+
+impl<E> fmt::Display for ContextError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.context)
+    }
+}
+
+impl<E: Error + 'static> Error for ContextError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_ref().map(|e| e as _)
+    }
+}
+
+/**
+ * Adds a context message to the error of a `Result`, or to the `None` of an
+ * `Option`.
+ *
+ * `with_context` only builds the message if there is an error, which is
+ * cheaper when it needs a `format!`.
+ */
+pub trait Context<T, E> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T, ContextError<E>>;
+
+    fn with_context<C, F>(self, context: F) -> Result<T, ContextError<E>>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C;
+}
+
+impl<T, E: Error> Context<T, E> for Result<T, E> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T, ContextError<E>> {
+        self.with_context(|| context)
+    }
+
+    fn with_context<C, F>(self, context: F) -> Result<T, ContextError<E>>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|source| ContextError {
+            context: context().to_string(),
+            source: Some(source),
+        })
+    }
+}
+
+impl<T> Context<T, Infallible> for Option<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T, ContextError> {
+        self.with_context(|| context)
+    }
+
+    fn with_context<C, F>(self, context: F) -> Result<T, ContextError>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C,
+    {
+        self.ok_or_else(|| ContextError {
+            context: context().to_string(),
+            source: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, io};
+
+    #[test]
+    fn should_wrap_the_error_and_keep_it_as_source() {
+        let error = fs::read_to_string("does/not/exist.txt")
+            .context("reading the user name")
+            .unwrap_err();
+
+        assert_eq!(error.to_string(), "reading the user name");
+        assert_eq!(error.inner().unwrap().kind(), io::ErrorKind::NotFound);
+        let source = error.source().unwrap();
+        assert!(source.downcast_ref::<io::Error>().is_some());
+    }
+
+    #[test]
+    fn should_stack_contexts() {
+        let error = "x"
+            .parse::<u8>()
+            .context("reading the port")
+            .with_context(|| format!("loading {}", "server.toml"))
+            .unwrap_err();
+
+        let chain: Vec<String> =
+            std::iter::successors(Some(&error as &dyn Error), |e| (*e).source())
+                .map(|e| e.to_string())
+                .collect();
+        assert_eq!(
+            chain,
+            [
+                "loading server.toml",
+                "reading the port",
+                "invalid digit found in string"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_turn_none_into_an_error_without_source() {
+        let error = None::<u8>.context("no user name given").unwrap_err();
+
+        assert_eq!(error.to_string(), "no user name given");
+        assert!(error.source().is_none());
+        assert_eq!(Some(1).context("unused").unwrap(), 1);
+    }
+
+    #[test]
+    fn should_build_the_message_only_on_error() {
+        let ok: Result<u8, io::Error> = Ok(1);
+
+        assert_eq!(
+            ok.with_context(|| -> String { panic!("must not be called") })
+                .unwrap(),
+            1
+        );
+    }
+}
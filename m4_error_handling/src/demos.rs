@@ -0,0 +1,279 @@
+//! The demos `m4 run` can pick from.
+//!
+//! Their summaries are the doc comments right above the demo functions, read
+//! from the sources, so they cannot go out of date.
+
+use crate::{e2_propagatoin, e3_custom_error, e4_context};
+use lib::delim;
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+};
+
+const E2: &str = include_str!("e2_propagatoin.rs");
+const E3: &str = include_str!("e3_custom_error.rs");
+const E4: &str = include_str!("e4_context.rs");
+
+/// Where a demo reads from, unless `--input` says otherwise.
+#[derive(Clone, Copy)]
+pub enum Run {
+    /// Lines typed on stdin.
+    Lines(fn(&mut dyn BufRead)),
+    /// A file with the user name, `hello.txt`.
+    File(fn(&Path) -> io::Result<()>),
+}
+
+pub struct Demo {
+    pub topic: &'static str,
+    /// Tells apart the demos of a topic that has several.
+    pub number: Option<usize>,
+    run: Run,
+    source: &'static str,
+    function: &'static str,
+}
+
+pub static DEMOS: &[Demo] = &[
+    Demo::new(
+        "propagation",
+        None,
+        Run::File(e2_propagatoin::run),
+        E2,
+        "run",
+    ),
+    Demo::new(
+        "custom-error",
+        Some(1),
+        Run::Lines(e3_custom_error::custom_error_demo1),
+        E3,
+        "custom_error_demo1",
+    ),
+    Demo::new(
+        "custom-error",
+        Some(2),
+        Run::Lines(e3_custom_error::custom_error_demo2),
+        E3,
+        "custom_error_demo2",
+    ),
+    Demo::new(
+        "custom-error",
+        Some(3),
+        Run::Lines(e3_custom_error::custom_error_demo3),
+        E3,
+        "custom_error_demo3",
+    ),
+    Demo::new(
+        "custom-error",
+        Some(4),
+        Run::Lines(e3_custom_error::custom_error_demo4),
+        E3,
+        "custom_error_demo4",
+    ),
+    Demo::new(
+        "custom-error",
+        Some(5),
+        Run::Lines(e3_custom_error::custom_error_demo5),
+        E3,
+        "custom_error_demo5",
+    ),
+    Demo::new(
+        "custom-error",
+        Some(6),
+        Run::Lines(e3_custom_error::custom_error_demo6),
+        E3,
+        "custom_error_demo6",
+    ),
+    Demo::new("context", None, Run::File(e4_context::run), E4, "run"),
+];
+
+impl Demo {
+    const fn new(
+        topic: &'static str,
+        number: Option<usize>,
+        run: Run,
+        source: &'static str,
+        function: &'static str,
+    ) -> Self {
+        Self {
+            topic,
+            number,
+            run,
+            source,
+            function,
+        }
+    }
+
+    /// What to pass to `m4 run`, e.g. `custom-error 4`.
+    pub fn name(&self) -> String {
+        match self.number {
+            Some(number) => format!("{} {number}", self.topic),
+            None => self.topic.to_string(),
+        }
+    }
+
+    /// The lines of the demo function's doc comment.
+    pub fn summary(&self) -> Vec<&'static str> {
+        doc_comment(self.source, self.function)
+    }
+
+    /// Runs the demo, reading from `input` if given.
+    pub fn run(&self, input: Option<&Path>) -> io::Result<()> {
+        let result = match self.run {
+            Run::Lines(run) => {
+                let mut lines: Box<dyn BufRead> = match input {
+                    Some(path) => {
+                        let file = File::open(path).map_err(|e| {
+                            io::Error::new(e.kind(), format!("cannot read {}: {e}", path.display()))
+                        })?;
+                        Box::new(BufReader::new(file))
+                    }
+                    None => Box::new(io::stdin().lock()),
+                };
+                delim!();
+                run(&mut lines);
+                Ok(())
+            }
+            Run::File(run) => {
+                delim!();
+                run(input.unwrap_or(Path::new("hello.txt")))
+            }
+        };
+        delim!();
+        result
+    }
+}
+
+/// The demo named by `topic` and, if the topic has several, `number`.
+pub fn find(topic: &str, number: Option<&str>) -> Result<&'static Demo, String> {
+    let demos: Vec<&Demo> = DEMOS.iter().filter(|d| d.topic == topic).collect();
+    if demos.is_empty() {
+        return Err(format!("There is no {topic:?} demo, see `list`."));
+    }
+
+    let number = match number {
+        Some(number) => Some(
+            number
+                .parse::<usize>()
+                .map_err(|_| format!("{number:?} is not a demo number."))?,
+        ),
+        None => None,
+    };
+    demos
+        .iter()
+        .find(|d| d.number == number)
+        .copied()
+        .ok_or_else(|| {
+            let numbers: Vec<String> = demos
+                .iter()
+                .flat_map(|d| d.number)
+                .map(|n| n.to_string())
+                .collect();
+            match &numbers[..] {
+                [] => format!("The {topic} demo takes no number."),
+                _ => format!("Pick one of the {topic} demos {}.", numbers.join(", ")),
+            }
+        })
+}
+
+/// Prints every demo with its summary.
+pub fn print_list(out: &mut impl Write) -> io::Result<()> {
+    for demo in DEMOS {
+        let summary = demo.summary();
+        let (title, bullets) = summary.split_first().unwrap_or((&"", &[]));
+        writeln!(out, "{:<16} {}", demo.name(), title.trim_end_matches(':'))?;
+        for bullet in bullets {
+            writeln!(out, "{:<16}   {bullet}", "")?;
+        }
+    }
+    Ok(())
+}
+
+/// The text of the `/** */` comment right above `pub fn {function}(`.
+fn doc_comment(source: &'static str, function: &str) -> Vec<&'static str> {
+    let signature = format!("pub fn {function}(");
+    let lines: Vec<&'static str> = source.lines().collect();
+    let Some(end) = lines.iter().position(|line| line.starts_with(&signature)) else {
+        return Vec::new();
+    };
+    if end == 0 || lines[end - 1].trim() != "*/" {
+        return Vec::new();
+    }
+    let start = lines[..end]
+        .iter()
+        .rposition(|line| line.trim() == "/**")
+        .unwrap_or(end - 1);
+
+    lines[start + 1..end - 1]
+        .iter()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_the_summary_of_every_demo() {
+        for demo in DEMOS {
+            let summary = demo.summary();
+            assert!(summary.len() > 1, "{} has no summary", demo.name());
+            assert!(summary[1..].iter().all(|line| line.starts_with("- ")));
+        }
+        assert_eq!(
+            find("custom-error", Some("4")).unwrap().summary()[..2],
+            [
+                "Method 4:",
+                "- Using `Result` and `enum` (io:Error, num::ParseIntError) as custom error type"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_ignore_comments_not_right_above_the_function() {
+        let source = "/**\n * Unrelated\n */\nstruct S;\n\npub fn demo() {}\n";
+        assert!(doc_comment(source, "demo").is_empty());
+        assert!(doc_comment(source, "missing").is_empty());
+    }
+
+    #[test]
+    fn should_fail_when_the_input_cannot_be_read() {
+        let missing = Path::new("no-such-input.txt");
+
+        for name in ["propagation", "context"] {
+            assert!(find(name, None).unwrap().run(Some(missing)).is_err());
+        }
+        let error = find("custom-error", Some("1"))
+            .unwrap()
+            .run(Some(missing))
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("cannot read no-such-input.txt: "));
+    }
+
+    #[test]
+    fn should_find_demos_by_topic_and_number() {
+        assert_eq!(find("context", None).unwrap().name(), "context");
+        assert_eq!(
+            find("custom-error", Some("6")).unwrap().name(),
+            "custom-error 6"
+        );
+
+        assert_eq!(
+            find("custom-error", None).err().unwrap(),
+            "Pick one of the custom-error demos 1, 2, 3, 4, 5, 6."
+        );
+        assert_eq!(
+            find("custom-error", Some("7")).err().unwrap(),
+            "Pick one of the custom-error demos 1, 2, 3, 4, 5, 6."
+        );
+        assert_eq!(
+            find("context", Some("1")).err().unwrap(),
+            "The context demo takes no number."
+        );
+        assert!(find("custom-error", Some("four")).is_err());
+        assert!(find("nope", None).is_err());
+    }
+}
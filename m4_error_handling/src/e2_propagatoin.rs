@@ -5,14 +5,44 @@ use std::{
     path::Path,
 };
 
-pub fn run() {
-    let dir = current_dir().unwrap();
+/**
+ * Error Propagation:
+ * - Reading a user name with `read_username_from_file`
+ * - Each `read-username-*` feature adds another way to propagate `io::Error`
+ */
+pub fn run(file_name: &Path) -> io::Result<()> {
+    let dir = current_dir()?;
     println!("Current dir: {:?}", dir);
 
-    match read_username_from_file("hello.txt") {
-        Ok(s) => println!("User name: {s}"),
-        Err(e) => println!("Error: {:?}", e),
-    };
+    show("match", file_name, read_username_from_file(file_name))?;
+    #[cfg(feature = "read-username-question-mark")]
+    show(
+        "?",
+        file_name,
+        read_username_from_file_question_mark(file_name),
+    )?;
+    #[cfg(feature = "read-username-chained")]
+    show(
+        "chained ?",
+        file_name,
+        read_username_from_file_chained(file_name),
+    )?;
+    #[cfg(feature = "read-username-fs")]
+    show(
+        "fs::read_to_string",
+        file_name,
+        read_username_from_file_fs(file_name),
+    )?;
+    Ok(())
+}
+
+fn show(version: &str, file_name: &Path, result: io::Result<String>) -> io::Result<()> {
+    let s = result.map_err(|e| {
+        let message = format!("cannot read {} ({version}): {e}", file_name.display());
+        io::Error::new(e.kind(), message)
+    })?;
+    println!("User name ({version}): {s}");
+    Ok(())
 }
 
 /**
@@ -21,7 +51,10 @@ pub fn run() {
 
 // type io::Result<T> = Result<T, io::Error>
 // Result<File, Error> => Result<String, Error>
-// #[cfg(feature = "skip")]
+//
+// Each alternative implementation below is enabled by its own feature, e.g.
+// `cargo run -p m4_error_handling --features read-username-question-mark -- run propagation`.
+// The features can be combined, and `run` tries every enabled version.
 pub fn read_username_from_file<P>(file_name: P) -> io::Result<String>
 where
     P: AsRef<Path>,
@@ -43,8 +76,8 @@ where
     }
 }
 
-#[cfg(feature = "skip")]
-pub fn read_username_from_file<P>(file_name: P) -> io::Result<String>
+#[cfg(feature = "read-username-question-mark")]
+pub fn read_username_from_file_question_mark<P>(file_name: P) -> io::Result<String>
 where
     P: AsRef<Path>,
 {
@@ -57,8 +90,8 @@ where
     Ok(s)
 }
 
-#[cfg(feature = "skip")]
-pub fn read_username_from_file<P>(file_name: P) -> io::Result<String>
+#[cfg(feature = "read-username-chained")]
+pub fn read_username_from_file_chained<P>(file_name: P) -> io::Result<String>
 where
     P: AsRef<Path>,
 {
@@ -67,8 +100,8 @@ where
     Ok(s)
 }
 
-#[cfg(feature = "skip")]
-pub fn read_username_from_file<P>(file_name: P) -> io::Result<String>
+#[cfg(feature = "read-username-fs")]
+pub fn read_username_from_file_fs<P>(file_name: P) -> io::Result<String>
 where
     P: AsRef<Path>,
 {
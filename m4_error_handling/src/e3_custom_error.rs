@@ -1,61 +1,54 @@
+use lib::report::eprint_report;
 use std::error;
-use std::io;
+use std::io::{self, BufRead};
 use std::num;
 
-pub fn run() {
-    custom_error_demo1();
-    // custom_error_demo2();
-    // custom_error_demo3();
-    // custom_error_demo4();
-    // custom_error_demo5();
-    // custom_error_demo6();
-}
-
 /**
-* Method 1:
+ * Method 1:
  * - Using `Result` and `String` as error type
  * - Using `match` to handle error
  */
-fn custom_error_demo1() {
-    fn do_it() -> Result<i32, String> {
+pub fn custom_error_demo1(input: &mut dyn BufRead) {
+    fn do_it(input: &mut dyn BufRead) -> Result<i32, String> {
         println!("Enter number: ");
 
         let mut buffer = String::new();
-        match io::stdin().read_line(&mut buffer) {
+        match input.read_line(&mut buffer) {
             Ok(_) => {}
-            Err(e) => return Err(format!("Error: {e:?}")),
+            Err(e) => return Err(format!("cannot read the number: {e}")),
         }
 
         let number = match buffer.trim().parse::<i32>() {
             Ok(n) => n,
-            Err(e) => return Err(format!("Error: {e:?}")),
+            Err(e) => return Err(format!("{:?} is not a number: {e}", buffer.trim())),
         };
 
         Ok(number)
     }
 
-    match do_it() {
+    match do_it(input) {
         Ok(n) => println!("You entered: {n}"),
-        Err(e) => println!("Error: {e:?}"),
+        // A `String` is no `Error`, but can be boxed into one.
+        Err(e) => eprint_report(&*Box::<dyn error::Error>::from(e)),
     }
 }
 
-/**
- * Method 2:
- * - Using `Result` and `enum` (io:Error, num::ParseIntError) as custom error type
- * - Using `match` to handle error
- */
 enum MyError {
     IoError(io::Error),
     ParseError(num::ParseIntError),
 }
 
-fn custom_error_demo2() {
-    fn do_it() -> Result<i32, MyError> {
+/**
+ * Method 2:
+ * - Using `Result` and `enum` (io:Error, num::ParseIntError) as custom error type
+ * - Using `match` to handle error
+ */
+pub fn custom_error_demo2(input: &mut dyn BufRead) {
+    fn do_it(input: &mut dyn BufRead) -> Result<i32, MyError> {
         println!("Enter number: ");
 
         let mut buffer = String::new();
-        match io::stdin().read_line(&mut buffer) {
+        match input.read_line(&mut buffer) {
             Ok(_) => {}
             Err(e) => return Err(MyError::IoError(e)),
         }
@@ -68,11 +61,11 @@ fn custom_error_demo2() {
         Ok(number)
     }
 
-    match do_it() {
+    match do_it(input) {
         Ok(n) => println!("You entered: {n}"),
         Err(e) => match e {
-            MyError::IoError(e) => println!("Error: {e:?}"),
-            MyError::ParseError(e) => println!("Error: {e:?}"),
+            MyError::IoError(e) => eprint_report(&e),
+            MyError::ParseError(e) => eprint_report(&e),
         },
     }
 }
@@ -83,37 +76,27 @@ fn custom_error_demo2() {
  * - Using `?` to handle error
  * - Using `map_err` to handle error
  */
-fn custom_error_demo3() {
-    fn do_it() -> Result<i32, MyError> {
+pub fn custom_error_demo3(input: &mut dyn BufRead) {
+    fn do_it(input: &mut dyn BufRead) -> Result<i32, MyError> {
         println!("Enter number: ");
 
         let mut buffer = String::new();
-        io::stdin()
-            .read_line(&mut buffer)
-            .map_err(MyError::IoError)?;
+        input.read_line(&mut buffer).map_err(MyError::IoError)?;
 
         let number = buffer.trim().parse::<i32>().map_err(MyError::ParseError)?;
 
         Ok(number)
     }
 
-    match do_it() {
+    match do_it(input) {
         Ok(n) => println!("You entered: {n}"),
         Err(e) => match e {
-            MyError::IoError(e) => println!("Error: {e:?}"),
-            MyError::ParseError(e) => println!("Error: {e:?}"),
+            MyError::IoError(e) => eprint_report(&e),
+            MyError::ParseError(e) => eprint_report(&e),
         },
     }
 }
 
-/**
- * Method 4:
- * - Using `Result` and `enum` (io:Error, num::ParseIntError) as custom error type
- * - Using `?` to handle error
- * - Using `From` trait to convert `io::Error` to `MyError`
- * - Using `From` trait to convert `num::ParseIntError` to `MyError`
- */
-
 impl From<io::Error> for MyError {
     fn from(e: io::Error) -> Self {
         MyError::IoError(e)
@@ -126,23 +109,30 @@ impl From<num::ParseIntError> for MyError {
     }
 }
 
-fn custom_error_demo4() {
-    fn do_it() -> Result<i32, MyError> {
+/**
+ * Method 4:
+ * - Using `Result` and `enum` (io:Error, num::ParseIntError) as custom error type
+ * - Using `?` to handle error
+ * - Using `From` trait to convert `io::Error` to `MyError`
+ * - Using `From` trait to convert `num::ParseIntError` to `MyError`
+ */
+pub fn custom_error_demo4(input: &mut dyn BufRead) {
+    fn do_it(input: &mut dyn BufRead) -> Result<i32, MyError> {
         println!("Enter number: ");
 
         let mut buffer = String::new();
-        io::stdin().read_line(&mut buffer)?;
+        input.read_line(&mut buffer)?;
 
         let number = buffer.trim().parse::<i32>()?;
 
         Ok(number)
     }
 
-    match do_it() {
+    match do_it(input) {
         Ok(n) => println!("You entered: {n}"),
         Err(e) => match e {
-            MyError::IoError(e) => println!("Error: {e:?}"),
-            MyError::ParseError(e) => println!("Error: {e:?}"),
+            MyError::IoError(e) => eprint_report(&e),
+            MyError::ParseError(e) => eprint_report(&e),
         },
     }
 }
@@ -152,21 +142,21 @@ fn custom_error_demo4() {
  * - Using `Result` and `Box<dyn error::Error>` as error type
  * - Using `?` to handle error
  */
-fn custom_error_demo5() {
-    fn do_it() -> Result<i32, Box<dyn error::Error>> {
+pub fn custom_error_demo5(input: &mut dyn BufRead) {
+    fn do_it(input: &mut dyn BufRead) -> Result<i32, Box<dyn error::Error>> {
         println!("Enter number: ");
 
         let mut buffer = String::new();
-        io::stdin().read_line(&mut buffer)?;
+        input.read_line(&mut buffer)?;
 
         let number = buffer.trim().parse::<i32>()?;
 
         Ok(number)
     }
 
-    match do_it() {
+    match do_it(input) {
         Ok(n) => println!("You entered: {n}"),
-        Err(e) => println!("Error: {e:?}"),
+        Err(e) => eprint_report(&*e),
     }
 }
 
@@ -178,32 +168,32 @@ fn custom_error_demo5() {
  * - Using `#[from]` to convert `io::Error` to `MyError`
  * - Using `#[from]` to convert `num::ParseIntError` to `MyError`
  * - Using `#[error]` to format error message
- *
+ * - `#[from]` also makes the wrapped error the `source`, shown as its cause
  */
-fn custom_error_demo6() {
+pub fn custom_error_demo6(input: &mut dyn BufRead) {
     use thiserror::Error;
 
     #[derive(Error, Debug)]
     enum MyError {
-        #[error("Error: {0:?}")]
+        #[error("cannot read the number")]
         IoError(#[from] io::Error),
-        #[error("Error: {0:?}")]
+        #[error("not a number")]
         ParseError(#[from] num::ParseIntError),
     }
 
-    fn do_it() -> Result<i32, MyError> {
+    fn do_it(input: &mut dyn BufRead) -> Result<i32, MyError> {
         println!("Enter number: ");
 
         let mut buffer = String::new();
-        io::stdin().read_line(&mut buffer)?;
+        input.read_line(&mut buffer)?;
 
         let number = buffer.trim().parse::<i32>()?;
 
         Ok(number)
     }
 
-    match do_it() {
+    match do_it(input) {
         Ok(n) => println!("You entered: {n}"),
-        Err(e) => println!("Error: {e:?}"),
+        Err(e) => eprint_report(&e),
     }
 }
@@ -0,0 +1,68 @@
+use lib::report::eprint_report;
+use std::{error, fs, io, path::Path};
+
+/**
+ * Context:
+ * - Reading a user name, saying which file could not be read
+ * - Once with `m4_error_handling::context::Context`, once with `anyhow::Context`
+ */
+pub fn run(file_name: &Path) -> io::Result<()> {
+    println!("With m4_error_handling::context::Context:");
+    let result = read_username_from_file(file_name);
+    match &result {
+        Ok(s) => println!("User name: {s}"),
+        Err(e) => eprint_report(&**e),
+    }
+
+    println!("With anyhow::Context:");
+    match read_username_from_file_anyhow(file_name) {
+        Ok(s) => println!("User name: {s}"),
+        // `{:?}` on an `anyhow::Error` prints the causes too
+        Err(e) => eprintln!("Error: {e:?}"),
+    }
+
+    // The reports above already said why
+    result
+        .map(drop)
+        .map_err(|_| io::Error::other(format!("no user name in {}", file_name.display())))
+}
+
+/**
+ * Context without anyhow
+ * - `.context()` wraps the `io::Error` in a `ContextError<io::Error>`
+ * - `.with_context()` builds the message only when there is an error
+ * - `Box<dyn Error>` lets `?` mix the `Result` and `Option` errors
+ */
+fn read_username_from_file<P>(file_name: P) -> Result<String, Box<dyn error::Error>>
+where
+    P: AsRef<Path>,
+{
+    use m4_error_handling::context::Context;
+
+    let file_name = file_name.as_ref();
+    let s = fs::read_to_string(file_name)
+        .with_context(|| format!("reading {}", file_name.display()))?;
+
+    let name = s.lines().next().context("the file is empty")?;
+    Ok(name.to_string())
+}
+
+/**
+ * Context with anyhow
+ * - The same calls, with `anyhow::Error` in place of `Box<dyn Error>`
+ * - `anyhow::Error` prints its causes with `{:?}`, and captures a backtrace
+ *   when `RUST_BACKTRACE=1` is set
+ */
+fn read_username_from_file_anyhow<P>(file_name: P) -> anyhow::Result<String>
+where
+    P: AsRef<Path>,
+{
+    use anyhow::Context;
+
+    let file_name = file_name.as_ref();
+    let s = fs::read_to_string(file_name)
+        .with_context(|| format!("reading {}", file_name.display()))?;
+
+    let name = s.lines().next().context("the file is empty")?;
+    Ok(name.to_string())
+}
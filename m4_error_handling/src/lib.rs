@@ -1,4 +1,8 @@
 #![allow(unused)]
 
+pub mod aggregate;
+pub mod context;
 mod e1_basics;
 mod exercises;
+pub mod numeric;
+pub mod streaming;
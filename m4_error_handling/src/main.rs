@@ -1,24 +1,59 @@
 #![allow(unused)]
 
+mod demos;
 mod e2_propagatoin;
 mod e3_custom_error;
+mod e4_context;
 
-use lib::delim;
-use std::env::current_dir;
+use std::{io, path::Path, process::ExitCode};
 
-fn main() {
-    delim!();
+const USAGE: &str = "\
+Usage: cargo run -p m4_error_handling -- <command>
 
-    // propagation_demo();
-    custom_error_demo();
+Commands:
+  list                            Show every demo and what it is about
+  run <demo> [n] [--input FILE]   Run a demo, e.g. `run custom-error 4`
 
-    delim!();
-}
+The custom-error demos read a number from stdin, the propagation and context
+demos read a user name from hello.txt. With --input they read FILE instead.";
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    let input = match args.iter().position(|arg| arg == "--input") {
+        Some(i) if i + 1 < args.len() => {
+            args.remove(i);
+            Some(args.remove(i))
+        }
+        Some(_) => {
+            println!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+        None => None,
+    };
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args[..] {
+        ["list"] => demos::print_list(&mut io::stdout()).map_err(|e| e.to_string()),
+        ["run", topic] => run(topic, None, input.as_deref()),
+        ["run", topic, number] => run(topic, Some(number), input.as_deref()),
+        _ => {
+            println!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
 
-fn propagation_demo() {
-    e2_propagatoin::run();
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
 }
 
-fn custom_error_demo() {
-    e3_custom_error::run();
+fn run(topic: &str, number: Option<&str>, input: Option<&str>) -> Result<(), String> {
+    demos::find(topic, number)?
+        .run(input.map(Path::new))
+        .map_err(|e| e.to_string())
 }
@@ -0,0 +1,334 @@
+//! `sum_of_vecs` for any number type, not just `i32`.
+//!
+//! Each type fails to parse in its own way, with a `ParseIntError`, a
+//! `ParseFloatError` or a `ParseDecimalError`. `ParseNumberError` wraps them
+//! all, so a generic caller has a single error type to handle.
+
+use crate::aggregate::{aggregate_with, AggregateError, Number, Op};
+use std::{
+    fmt,
+    num::{ParseFloatError, ParseIntError},
+    str::FromStr,
+};
+use thiserror::Error;
+
+/// A fixed-point decimal number with `Decimal::PLACES` digits after the point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Decimal {
+    /// The value in units of `10^-PLACES`, e.g. `12345` for `1.2345`.
+    units: i64,
+}
+
+impl Decimal {
+    pub const PLACES: u32 = 4;
+    const SCALE: i64 = 10_i64.pow(Self::PLACES);
+
+    pub const MIN: Decimal = Decimal::from_units(i64::MIN);
+    pub const MAX: Decimal = Decimal::from_units(i64::MAX);
+
+    pub const fn from_units(units: i64) -> Self {
+        Self { units }
+    }
+
+    pub fn units(self) -> i64 {
+        self.units
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.units < 0 { "-" } else { "" };
+        let units = self.units.unsigned_abs();
+        let scale = Self::SCALE.unsigned_abs();
+        write!(
+            f,
+            "{sign}{}.{:0places$}",
+            units / scale,
+            units % scale,
+            places = Self::PLACES as usize
+        )
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseDecimalError {
+    #[error("cannot parse decimal from empty string")]
+    Empty,
+    #[error("invalid digit found in string")]
+    InvalidDigit,
+    #[error("more than {} digits after the decimal point", Decimal::PLACES)]
+    TooManyPlaces,
+    #[error("number too large to fit in a decimal")]
+    Overflow,
+}
+
+/// Reads an optional sign, digits and an optional fraction, e.g. `-12.5`.
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        if int.is_empty() && frac.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+        if !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) {
+            return Err(ParseDecimalError::InvalidDigit);
+        }
+        if frac.len() > Self::PLACES as usize {
+            return Err(ParseDecimalError::TooManyPlaces);
+        }
+
+        // Accumulate towards the sign so that `i64::MIN` units still fit.
+        let sign = if negative { -1 } else { 1 };
+        let padding = Self::PLACES as usize - frac.len();
+        let mut units: i64 = 0;
+        for digit in int
+            .bytes()
+            .chain(frac.bytes())
+            .chain(std::iter::repeat_n(b'0', padding))
+        {
+            units = units
+                .checked_mul(10)
+                .and_then(|units| units.checked_add(sign * i64::from(digit - b'0')))
+                .ok_or(ParseDecimalError::Overflow)?;
+        }
+        Ok(Self::from_units(units))
+    }
+}
+
+impl Number for Decimal {
+    const ZERO: Self = Decimal::from_units(0);
+    const ONE: Self = Decimal::from_units(Decimal::SCALE);
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.units.checked_add(rhs.units).map(Self::from_units)
+    }
+
+    /// Rounds toward zero to `PLACES` digits.
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let product = i128::from(self.units) * i128::from(rhs.units) / i128::from(Self::SCALE);
+        i64::try_from(product).ok().map(Self::from_units)
+    }
+
+    fn mean(self, count: usize) -> Self {
+        Self::from_units(i64::try_from(count).map_or(0, |count| self.units / count))
+    }
+}
+
+/// Why a string is not a number, whichever type it was parsed as.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseNumberError {
+    #[error(transparent)]
+    Int(#[from] ParseIntError),
+    #[error(transparent)]
+    Float(#[from] ParseFloatError),
+    #[error(transparent)]
+    Decimal(#[from] ParseDecimalError),
+    /// `NaN` parses as a float, but would make any sum `NaN` too.
+    #[error("NaN is not allowed")]
+    NaN,
+}
+
+/// A number type `sum_of_vecs` can parse and add up.
+pub trait Numeric: Number + fmt::Display {
+    fn parse_number(s: &str) -> Result<Self, ParseNumberError>;
+}
+
+macro_rules! impl_numeric_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn parse_number(s: &str) -> Result<Self, ParseNumberError> {
+                    Ok(s.parse()?)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_numeric_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn parse_number(s: &str) -> Result<Self, ParseNumberError> {
+                    let n: $t = s.parse()?;
+                    if n.is_nan() {
+                        return Err(ParseNumberError::NaN);
+                    }
+                    Ok(n)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_for_floats!(f32, f64);
+
+impl Numeric for Decimal {
+    fn parse_number(s: &str) -> Result<Self, ParseNumberError> {
+        Ok(s.parse()?)
+    }
+}
+
+/// Sums the strings as numbers of type `N`, like the `sum_of_vecs`
+/// exercises do for `i32`.
+pub fn sum_of_vecs<N: Numeric>(
+    vs: Vec<String>,
+) -> Result<String, AggregateError<ParseNumberError>> {
+    aggregate_with(vs, Op::Sum, N::parse_number).map(|sum: N| sum.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::IntErrorKind;
+
+    fn strings(vs: &[&str]) -> Vec<String> {
+        vs.iter().map(|v| v.to_string()).collect()
+    }
+
+    /// The parse error `sum_of_vecs` ran into, if that is why it failed.
+    fn parse_error<N: Numeric>(vs: &[&str]) -> Option<ParseNumberError> {
+        match sum_of_vecs::<N>(strings(vs)) {
+            Err(AggregateError::Parse { source, .. }) => Some(source),
+            _ => None,
+        }
+    }
+
+    fn overflows<N: Numeric>(vs: &[&str]) -> bool {
+        matches!(
+            sum_of_vecs::<N>(strings(vs)),
+            Err(AggregateError::Overflow { .. })
+        )
+    }
+
+    #[test]
+    fn should_sum_integers() {
+        assert_eq!(
+            sum_of_vecs::<i64>(strings(&["1", "-2", "3"])),
+            Ok("2".to_string())
+        );
+        assert_eq!(
+            sum_of_vecs::<i64>(strings(&["2147483647", "1"])),
+            Ok("2147483648".to_string())
+        );
+        assert_eq!(
+            sum_of_vecs::<u128>(strings(&["18446744073709551615", "1"])),
+            Ok("18446744073709551616".to_string())
+        );
+
+        assert!(overflows::<i64>(&["9223372036854775807", "1"]));
+        assert!(overflows::<u128>(&[
+            "340282366920938463463374607431768211455",
+            "1"
+        ]));
+        assert!(matches!(
+            parse_error::<u128>(&["-1"]),
+            Some(ParseNumberError::Int(e)) if *e.kind() == IntErrorKind::InvalidDigit
+        ));
+    }
+
+    #[test]
+    fn should_sum_floats_and_reject_nan() {
+        assert_eq!(
+            sum_of_vecs::<f64>(strings(&["0.5", "1e2", "-0.25"])),
+            Ok("100.25".to_string())
+        );
+        assert_eq!(
+            sum_of_vecs::<f64>(strings(&["inf", "1"])),
+            Ok("inf".to_string())
+        );
+
+        assert!(overflows::<f64>(&["1e308", "1e308"]));
+        // `inf - inf` has no value, which counts as overflow as well.
+        assert!(overflows::<f64>(&["inf", "-inf"]));
+
+        assert_eq!(
+            parse_error::<f64>(&["1", "NaN"]),
+            Some(ParseNumberError::NaN)
+        );
+        assert_eq!(parse_error::<f32>(&["nan"]), Some(ParseNumberError::NaN));
+        assert!(matches!(
+            parse_error::<f64>(&["1,5"]),
+            Some(ParseNumberError::Float(_))
+        ));
+    }
+
+    #[test]
+    fn should_sum_decimals_exactly() {
+        // 0.1 + 0.2 is not 0.3 in binary floating point.
+        assert_ne!(
+            sum_of_vecs::<f64>(strings(&["0.1", "0.2"])),
+            Ok("0.3".to_string())
+        );
+        assert_eq!(
+            sum_of_vecs::<Decimal>(strings(&["0.1", "0.2"])),
+            Ok("0.3000".to_string())
+        );
+        assert_eq!(
+            sum_of_vecs::<Decimal>(strings(&["-1.25", ".5", "+2", "3."])),
+            Ok("4.2500".to_string())
+        );
+
+        assert!(overflows::<Decimal>(&["922337203685477.5807", "0.0001"]));
+        assert_eq!(
+            parse_error::<Decimal>(&["1.23456"]),
+            Some(ParseNumberError::Decimal(ParseDecimalError::TooManyPlaces))
+        );
+    }
+
+    #[test]
+    fn should_parse_and_display_decimals() {
+        assert_eq!("-0.5".parse(), Ok(Decimal::from_units(-5000)));
+        assert_eq!(Decimal::from_units(-5000).to_string(), "-0.5000");
+        assert_eq!(Decimal::MIN.to_string(), "-922337203685477.5808");
+        assert_eq!(Decimal::MIN.to_string().parse(), Ok(Decimal::MIN));
+        assert_eq!(Decimal::MAX.to_string().parse(), Ok(Decimal::MAX));
+
+        assert_eq!("".parse::<Decimal>(), Err(ParseDecimalError::Empty));
+        assert_eq!("-.".parse::<Decimal>(), Err(ParseDecimalError::Empty));
+        assert_eq!(
+            "1.2.3".parse::<Decimal>(),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!(
+            "1e3".parse::<Decimal>(),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!(
+            "922337203685477.5808".parse::<Decimal>(),
+            Err(ParseDecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn should_multiply_decimals_and_report_errors_uniformly() {
+        let product = aggregate_with(["1.5", "2.25"], Op::Product, Decimal::parse_number);
+        assert_eq!(product, Ok(Decimal::from_units(33750)));
+
+        let errors: Vec<String> = [
+            parse_error::<i32>(&["x"]),
+            parse_error::<f64>(&["x"]),
+            parse_error::<Decimal>(&["x"]),
+            parse_error::<f64>(&["NaN"]),
+        ]
+        .into_iter()
+        .map(|e| e.unwrap().to_string())
+        .collect();
+        assert_eq!(
+            errors,
+            [
+                "invalid digit found in string",
+                "invalid float literal",
+                "invalid digit found in string",
+                "NaN is not allowed",
+            ]
+        );
+    }
+}
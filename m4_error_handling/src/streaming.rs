@@ -0,0 +1,271 @@
+//! `sum_of_vecs` over a reader, for inputs too large to collect first.
+//!
+//! Entries are separated by newlines or commas, so both a column of numbers
+//! and a line like `1, 2, 3` work. Blank entries are skipped. Errors say in
+//! which input and on which line the bad entry is, like `data.txt:12`.
+
+use crate::numeric::{Numeric, ParseNumberError};
+use std::{
+    io::{self, BufRead},
+    str,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SumError {
+    #[error("{name}:{line}: {entry:?} is not a number: {source}")]
+    Parse {
+        name: String,
+        line: usize,
+        entry: String,
+        #[source]
+        source: ParseNumberError,
+    },
+    #[error("{name}:{line}: the sum overflowed at {entry:?}")]
+    Overflow {
+        name: String,
+        line: usize,
+        entry: String,
+    },
+    #[error("{name}:{line}: cannot read: {source}")]
+    Io {
+        name: String,
+        line: usize,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// A running total over any number of readers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sum<N> {
+    total: N,
+    count: usize,
+}
+
+impl<N: Numeric> Default for Sum<N> {
+    fn default() -> Self {
+        Self {
+            total: N::ZERO,
+            count: 0,
+        }
+    }
+}
+
+impl<N: Numeric> Sum<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn total(&self) -> N {
+        self.total
+    }
+
+    /// How many entries were added.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /**
+     * Adds every entry of `reader`, one at a time, so that not even a long
+     * line is held in memory in full. `name` stands for the reader in
+     * errors, e.g. a file name or `<stdin>`.
+     *
+     * On error the total keeps the entries before the bad one.
+     */
+    pub fn add_reader(&mut self, name: &str, mut reader: impl BufRead) -> Result<(), SumError> {
+        let io_error = |line, source| SumError::Io {
+            name: name.to_string(),
+            line,
+            source,
+        };
+
+        let mut entry = Vec::new();
+        let mut line = 1;
+        loop {
+            entry.clear();
+            let delimiter = read_entry(&mut reader, &mut entry).map_err(|e| io_error(line, e))?;
+            let entry = str::from_utf8(&entry)
+                .map_err(|e| io_error(line, io::Error::new(io::ErrorKind::InvalidData, e)))?
+                .trim();
+
+            if !entry.is_empty() {
+                let value = N::parse_number(entry).map_err(|source| SumError::Parse {
+                    name: name.to_string(),
+                    line,
+                    entry: entry.to_string(),
+                    source,
+                })?;
+                self.total = self
+                    .total
+                    .checked_add(value)
+                    .ok_or_else(|| SumError::Overflow {
+                        name: name.to_string(),
+                        line,
+                        entry: entry.to_string(),
+                    })?;
+                self.count += 1;
+            }
+
+            match delimiter {
+                Some(b'\n') => line += 1,
+                Some(_) => {}
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Appends the bytes up to the next comma or newline to `entry`, and returns
+/// that delimiter, or `None` at the end of the input.
+fn read_entry(reader: &mut impl BufRead, entry: &mut Vec<u8>) -> io::Result<Option<u8>> {
+    loop {
+        let available = match reader.fill_buf() {
+            Ok(available) => available,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if available.is_empty() {
+            return Ok(None);
+        }
+
+        match available.iter().position(|&b| b == b',' || b == b'\n') {
+            Some(i) => {
+                let delimiter = available[i];
+                entry.extend_from_slice(&available[..i]);
+                reader.consume(i + 1);
+                return Ok(Some(delimiter));
+            }
+            None => {
+                let len = available.len();
+                entry.extend_from_slice(available);
+                reader.consume(len);
+            }
+        }
+    }
+}
+
+/// Sums the entries of a single reader.
+pub fn sum_reader<N: Numeric>(name: &str, reader: impl BufRead) -> Result<N, SumError> {
+    let mut sum = Sum::new();
+    sum.add_reader(name, reader)?;
+    Ok(sum.total())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numeric::Decimal;
+    use std::io::Read;
+
+    /// A reader that fails after its first line.
+    struct BrokenReader {
+        first: Option<&'static [u8]>,
+    }
+
+    impl Read for BrokenReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.first.take() {
+                Some(first) => {
+                    buf[..first.len()].copy_from_slice(first);
+                    Ok(first.len())
+                }
+                None => Err(io::Error::other("disk on fire")),
+            }
+        }
+    }
+
+    #[test]
+    fn should_sum_newline_and_comma_separated_entries() {
+        let input = "1\n2, 3,4\r\n\n  ,5 \n6";
+
+        assert_eq!(sum_reader::<i64>("input", input.as_bytes()).unwrap(), 21);
+        assert_eq!(
+            sum_reader::<Decimal>("input", "0.1\n0.2".as_bytes()).unwrap(),
+            "0.3".parse().unwrap()
+        );
+        assert_eq!(sum_reader::<u8>("empty", "".as_bytes()).unwrap(), 0);
+    }
+
+    #[test]
+    fn should_keep_a_total_across_readers() {
+        let mut sum = Sum::<f64>::new();
+        sum.add_reader("a", "1.5\n2.5".as_bytes()).unwrap();
+        sum.add_reader("b", "-1".as_bytes()).unwrap();
+
+        assert_eq!((sum.total(), sum.count()), (3.0, 3));
+    }
+
+    #[test]
+    fn should_report_name_and_line_of_bad_entry() {
+        let input = "1,2\n\n3, 4x ,5\n";
+        let error = sum_reader::<i32>("numbers.txt", input.as_bytes()).unwrap_err();
+
+        assert!(matches!(
+            &error,
+            SumError::Parse { line: 3, entry, .. } if entry == "4x"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "numbers.txt:3: \"4x\" is not a number: invalid digit found in string"
+        );
+
+        let error = sum_reader::<u8>("<stdin>", "200\n100".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "<stdin>:2: the sum overflowed at \"100\""
+        );
+    }
+
+    /// One endless line: `1,x,` followed by `1,` over and over.
+    struct EndlessLine {
+        start: &'static [u8],
+    }
+
+    impl Read for EndlessLine {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(2);
+            let (head, rest) = self.start.split_at(self.start.len().min(n));
+            self.start = rest;
+            buf[..head.len()].copy_from_slice(head);
+            buf[head.len()..n].copy_from_slice(&b"1,"[head.len()..n]);
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn should_read_a_long_line_one_entry_at_a_time() {
+        let line = "12,".repeat(100_000);
+        let reader = io::BufReader::with_capacity(8, line.as_bytes());
+        assert_eq!(sum_reader::<u64>("long", reader).unwrap(), 1_200_000);
+
+        // Reading the whole line first would never get to the bad entry.
+        let reader = io::BufReader::with_capacity(8, EndlessLine { start: b"1,x," });
+        let error = sum_reader::<u64>("endless", reader).unwrap_err();
+        assert!(matches!(
+            error,
+            SumError::Parse { line: 1, entry, .. } if entry == "x"
+        ));
+    }
+
+    #[test]
+    fn should_report_invalid_utf8_as_a_read_error() {
+        let error = sum_reader::<i32>("binary", &b"1\n\xff\n"[..]).unwrap_err();
+
+        assert!(matches!(
+            error,
+            SumError::Io { line: 2, source, .. } if source.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn should_report_read_errors_with_their_line() {
+        let reader = io::BufReader::new(BrokenReader {
+            first: Some(b"1\n"),
+        });
+        let error = sum_reader::<i32>("flaky", reader).unwrap_err();
+
+        assert!(matches!(error, SumError::Io { line: 2, .. }));
+        assert_eq!(error.to_string(), "flaky:2: cannot read: disk on fire");
+    }
+}
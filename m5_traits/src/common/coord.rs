@@ -0,0 +1,230 @@
+//! Arithmetic, distances and parsing for `Coord`.
+
+use super::Coord;
+use std::{
+    error, fmt,
+    num::ParseIntError,
+    ops::{Add, Neg, Sub},
+    str::FromStr,
+};
+
+impl Coord {
+    /// The number of unit steps along the axes between the two points.
+    pub fn manhattan_distance(self, other: Coord) -> u64 {
+        u64::from(self.x.abs_diff(other.x)) + u64::from(self.y.abs_diff(other.y))
+    }
+
+    /// The straight-line distance between the two points.
+    pub fn euclidean_distance(self, other: Coord) -> f64 {
+        let dx = f64::from(self.x) - f64::from(other.x);
+        let dy = f64::from(self.y) - f64::from(other.y);
+        dx.hypot(dy)
+    }
+
+    /// `self + other`, or `None` if a coordinate overflows.
+    pub fn checked_add(self, other: Coord) -> Option<Coord> {
+        Some(Coord::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    /// `self - other`, or `None` if a coordinate overflows.
+    pub fn checked_sub(self, other: Coord) -> Option<Coord> {
+        Some(Coord::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    /// `-self`, or `None` if a coordinate is `i32::MIN`.
+    pub fn checked_neg(self) -> Option<Coord> {
+        Some(Coord::new(self.x.checked_neg()?, self.y.checked_neg()?))
+    }
+}
+
+/// Panics on overflow in debug builds and wraps in release, like `i32`; see
+/// `checked_add`.
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, other: Coord) -> Coord {
+        Coord::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+/// Panics on overflow in debug builds and wraps in release, like `i32`; see
+/// `checked_sub`.
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, other: Coord) -> Coord {
+        Coord::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Panics on `i32::MIN` in debug builds and wraps in release, like `i32`; see
+/// `checked_neg`.
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Coord {
+        Coord::new(-self.x, -self.y)
+    }
+}
+
+impl From<(i32, i32)> for Coord {
+    fn from((x, y): (i32, i32)) -> Self {
+        Coord::new(x, y)
+    }
+}
+
+/// Written the way `FromStr` reads it, e.g. `(1,-2)`.
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// Why a string is not a `Coord` like `(1,-2)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCoordError {
+    /// The string is not wrapped in parentheses.
+    MissingParentheses,
+    /// There is no comma, or more than one, between the parentheses.
+    WrongArity,
+    /// A coordinate is not an `i32`.
+    InvalidNumber { text: String, source: ParseIntError },
+}
+
+impl fmt::Display for ParseCoordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCoordError::MissingParentheses => {
+                write!(f, "a coordinate must be wrapped in parentheses, like (1,2)")
+            }
+            ParseCoordError::WrongArity => {
+                write!(f, "a coordinate must have exactly two numbers, like (1,2)")
+            }
+            ParseCoordError::InvalidNumber { text, source } => {
+                write!(f, "`{text}` is not a valid coordinate: {source}")
+            }
+        }
+    }
+}
+
+impl error::Error for ParseCoordError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ParseCoordError::InvalidNumber { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Reads `(x,y)`, allowing whitespace around the parts, e.g. `( 1, -2 )`.
+impl FromStr for Coord {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .trim()
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or(ParseCoordError::MissingParentheses)?;
+
+        let number = |text: &str| {
+            let text = text.trim();
+            text.parse()
+                .map_err(|source| ParseCoordError::InvalidNumber {
+                    text: text.to_string(),
+                    source,
+                })
+        };
+        let mut parts = inner.split(',');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(x), Some(y), None) => Ok(Coord::new(number(x)?, number(y)?)),
+            _ => Err(ParseCoordError::WrongArity),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_add_subtract_and_negate() {
+        let a = Coord::new(1, 2);
+        let b = Coord::from((3, -4));
+
+        assert_eq!(a + b, Coord::new(4, -2));
+        assert_eq!(a - b, Coord::new(-2, 6));
+        assert_eq!(-a, Coord::new(-1, -2));
+        assert_eq!(a + -a, Coord::new(0, 0));
+    }
+
+    #[test]
+    fn should_check_for_overflow() {
+        let a = Coord::new(1, 2);
+        let max = Coord::new(i32::MAX, 0);
+        let min = Coord::new(0, i32::MIN);
+
+        assert_eq!(a.checked_add(-a), Some(Coord::new(0, 0)));
+        assert_eq!(a.checked_sub(a), Some(Coord::new(0, 0)));
+        assert_eq!(a.checked_neg(), Some(-a));
+        assert_eq!(max.checked_add(a), None);
+        assert_eq!(min.checked_sub(a), None);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(max.checked_neg(), Some(Coord::new(-i32::MAX, 0)));
+    }
+
+    #[test]
+    fn should_measure_distances() {
+        let a = Coord::new(1, 2);
+        let b = Coord::new(4, -2);
+
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.euclidean_distance(b), 5.0);
+        assert_eq!(b.euclidean_distance(a), 5.0);
+
+        let corners = (
+            Coord::new(i32::MIN, i32::MIN),
+            Coord::new(i32::MAX, i32::MAX),
+        );
+        assert_eq!(
+            corners.0.manhattan_distance(corners.1),
+            2 * u64::from(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn should_parse_what_it_displays() {
+        for coord in [Coord::new(1, -2), Coord::new(i32::MIN, i32::MAX)] {
+            assert_eq!(coord.to_string().parse(), Ok(coord));
+        }
+        assert_eq!(" ( 3 , 4 ) ".parse(), Ok(Coord::new(3, 4)));
+    }
+
+    #[test]
+    fn should_reject_malformed_coordinates() {
+        assert_eq!(
+            "1,2".parse::<Coord>(),
+            Err(ParseCoordError::MissingParentheses)
+        );
+        assert_eq!(
+            "(1,2".parse::<Coord>(),
+            Err(ParseCoordError::MissingParentheses)
+        );
+        assert_eq!("(1)".parse::<Coord>(), Err(ParseCoordError::WrongArity));
+        assert_eq!("(1,2,3)".parse::<Coord>(), Err(ParseCoordError::WrongArity));
+        assert_eq!(
+            "(1,two)".parse::<Coord>().unwrap_err().to_string(),
+            "`two` is not a valid coordinate: invalid digit found in string"
+        );
+        assert_eq!(
+            "(1,)".parse::<Coord>().unwrap_err().to_string(),
+            "`` is not a valid coordinate: cannot parse integer from empty string"
+        );
+    }
+}
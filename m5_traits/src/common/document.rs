@@ -0,0 +1,194 @@
+//! A JSON document made of named sections of any `ToJson` type.
+
+use super::{json, ToJson};
+use std::{fmt, io};
+
+/**
+ * Named sections of different types, written as one JSON object with a
+ * member per section. Sections keep the order they were first inserted in.
+ */
+#[derive(Default)]
+pub struct Document {
+    sections: Vec<(String, Box<dyn ToJson>)>,
+}
+
+impl Document {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a section, or replaces the one of the same name in place and
+    /// returns it.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        section: impl ToJson + 'static,
+    ) -> Option<Box<dyn ToJson>> {
+        self.insert_boxed(name, Box::new(section))
+    }
+
+    /// Like `insert`, for a section that is already boxed.
+    pub fn insert_boxed(
+        &mut self,
+        name: impl Into<String>,
+        section: Box<dyn ToJson>,
+    ) -> Option<Box<dyn ToJson>> {
+        let name = name.into();
+        match self.position(&name) {
+            Some(i) => Some(std::mem::replace(&mut self.sections[i].1, section)),
+            None => {
+                self.sections.push((name, section));
+                None
+            }
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn ToJson> {
+        self.position(name).map(|i| &*self.sections[i].1)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.position(name).is_some()
+    }
+
+    /// Takes a section out; the sections after it keep their order.
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn ToJson>> {
+        self.position(name).map(|i| self.sections.remove(i).1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.sections.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &dyn ToJson)> {
+        self.sections
+            .iter()
+            .map(|(name, section)| (name.as_str(), &**section))
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.sections.iter().position(|(n, _)| n == name)
+    }
+}
+
+impl fmt::Debug for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Document")
+            .field("sections", &self.names().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl ToJson for Document {
+    fn to_json(&self) -> String {
+        let fields: Vec<(&str, &dyn ToJson)> = self.iter().collect();
+        json::object(&fields)
+    }
+
+    /// Writes one section at a time rather than the whole document at once.
+    fn write_json<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(b"{")?;
+        for (i, (name, section)) in self.iter().enumerate() {
+            let mut key = String::new();
+            if i > 0 {
+                key.push(',');
+            }
+            json::escape_str(name, &mut key);
+            key.push(':');
+            writer.write_all(key.as_bytes())?;
+            writer.write_all(section.to_json().as_bytes())?;
+        }
+        writer.write_all(b"}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Coord, Person};
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn document() -> Document {
+        let mut document = Document::new();
+        document.insert("author", Person::new("John", 42));
+        document.insert("origin", Coord::new(0, 0));
+        document.insert("tags", vec!["rust", "traits"]);
+        document.insert("draft", true);
+        document
+    }
+
+    #[test]
+    fn sections_are_written_in_insertion_order() {
+        let document = document();
+        let json = r#"{"author":{"name":"John","age":42},"origin":{"x":0,"y":0},"tags":["rust","traits"],"draft":true}"#;
+        assert_eq!(document.to_json(), json);
+
+        let mut out = Vec::new();
+        document.write_json(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), json);
+
+        assert_eq!(Document::new().to_json(), "{}");
+        assert_eq!(
+            format!("{document:?}"),
+            r#"Document { sections: ["author", "origin", "tags", "draft"] }"#
+        );
+    }
+
+    #[test]
+    fn sections_can_be_looked_up_replaced_and_removed() {
+        let mut document = document();
+        assert_eq!(
+            document.get("origin").map(|s| s.to_json()),
+            Some(r#"{"x":0,"y":0}"#.to_string())
+        );
+        assert!(document.get("missing").is_none());
+
+        let old = document.insert("origin", Coord::new(3, 4));
+        assert_eq!(
+            old.map(|s| s.to_json()),
+            Some(r#"{"x":0,"y":0}"#.to_string())
+        );
+        assert_eq!(
+            document.names().collect::<Vec<_>>(),
+            ["author", "origin", "tags", "draft"]
+        );
+
+        let removed = document.remove("author").unwrap();
+        assert_eq!(removed.to_json(), r#"{"name":"John","age":42}"#);
+        assert!(document.remove("author").is_none());
+        assert!(!document.contains("author"));
+        assert_eq!(document.len(), 3);
+        assert_eq!(
+            document.to_json(),
+            r#"{"origin":{"x":3,"y":4},"tags":["rust","traits"],"draft":true}"#
+        );
+    }
+
+    #[test]
+    fn boxed_sections_and_nested_documents() {
+        let section: Box<dyn ToJson> = Box::new(BTreeMap::from([("k", 1)]));
+        let mut inner = Document::new();
+        inner.insert_boxed("map", section);
+
+        let mut outer = Document::new();
+        outer.insert("inner", inner);
+        outer.insert("escaped \"name\"", ());
+        assert_eq!(
+            outer.to_json(),
+            r#"{"inner":{"map":{"k":1}},"escaped \"name\"":null}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&outer.to_json_pretty(2)).unwrap()["inner"]
+                ["map"]["k"],
+            1
+        );
+    }
+}
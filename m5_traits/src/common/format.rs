@@ -0,0 +1,202 @@
+//! Picking an output format at runtime.
+//!
+//! Each format has its own `Serializer` type, so a function that returns one
+//! of several of them cannot return `impl Serializer<T>`: that still means a
+//! single concrete type. `serializer` returns a `Box<dyn Serializer<T>>`
+//! instead, while `json_serializer` has only one type to return and gets
+//! away with `impl Serializer<T>`.
+
+use super::{toml::TomlError, ToCsv, ToJson, ToToml, ToYaml};
+use std::{error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Yaml,
+    Toml,
+    Csv,
+}
+
+impl Format {
+    pub const ALL: [Format; 4] = [Format::Json, Format::Yaml, Format::Toml, Format::Csv];
+
+    /// The usual file extension, without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Yaml => "yaml",
+            Format::Toml => "toml",
+            Format::Csv => "csv",
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(Format::Json),
+            "yaml" | "yml" => Ok(Format::Yaml),
+            "toml" => Ok(Format::Toml),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format `{s}`, expected json, yaml, toml or csv"
+            )),
+        }
+    }
+}
+
+/// Why a `Serializer` could not write a value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SerializeError {
+    /// TOML has no way to write some values.
+    Toml(TomlError),
+}
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerializeError::Toml(e) => write!(f, "cannot write TOML: {e}"),
+        }
+    }
+}
+
+impl error::Error for SerializeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            SerializeError::Toml(e) => Some(e),
+        }
+    }
+}
+
+impl From<TomlError> for SerializeError {
+    fn from(e: TomlError) -> Self {
+        SerializeError::Toml(e)
+    }
+}
+
+/// Writes values of type `T` in one particular format.
+pub trait Serializer<T: ?Sized> {
+    fn format(&self) -> Format;
+
+    fn serialize(&self, value: &T) -> Result<String, SerializeError>;
+}
+
+/// Everything that can be written in every `Format`.
+pub trait Serializable: ToJson + ToYaml + ToToml + ToCsv {}
+
+impl<T: ToJson + ToYaml + ToToml + ToCsv + ?Sized> Serializable for T {}
+
+pub struct JsonSerializer;
+pub struct YamlSerializer;
+pub struct TomlSerializer;
+pub struct CsvSerializer;
+
+impl<T: ToJson + ?Sized> Serializer<T> for JsonSerializer {
+    fn format(&self) -> Format {
+        Format::Json
+    }
+
+    fn serialize(&self, value: &T) -> Result<String, SerializeError> {
+        Ok(value.to_json())
+    }
+}
+
+impl<T: ToYaml + ?Sized> Serializer<T> for YamlSerializer {
+    fn format(&self) -> Format {
+        Format::Yaml
+    }
+
+    fn serialize(&self, value: &T) -> Result<String, SerializeError> {
+        Ok(value.to_yaml())
+    }
+}
+
+impl<T: ToToml + ?Sized> Serializer<T> for TomlSerializer {
+    fn format(&self) -> Format {
+        Format::Toml
+    }
+
+    fn serialize(&self, value: &T) -> Result<String, SerializeError> {
+        Ok(value.to_toml()?)
+    }
+}
+
+impl<T: ToCsv + ?Sized> Serializer<T> for CsvSerializer {
+    fn format(&self) -> Format {
+        Format::Csv
+    }
+
+    fn serialize(&self, value: &T) -> Result<String, SerializeError> {
+        Ok(value.to_csv())
+    }
+}
+
+/// The serializer for `format`. Each arm is a different type, hence the box.
+pub fn serializer<T: Serializable + ?Sized>(format: Format) -> Box<dyn Serializer<T>> {
+    match format {
+        Format::Json => Box::new(JsonSerializer),
+        Format::Yaml => Box::new(YamlSerializer),
+        Format::Toml => Box::new(TomlSerializer),
+        Format::Csv => Box::new(CsvSerializer),
+    }
+}
+
+/// A single serializer type, so `impl Trait` is enough.
+pub fn json_serializer<T: ToJson + ?Sized>() -> impl Serializer<T> {
+    JsonSerializer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Person;
+    use super::*;
+
+    #[test]
+    fn formats_parse_from_names_and_extensions() {
+        for format in Format::ALL {
+            assert_eq!(format.to_string().parse(), Ok(format));
+        }
+        assert_eq!("YML".parse(), Ok(Format::Yaml));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn serializer_dispatches_on_format() {
+        let person = Person::new("John", 42);
+        let outputs: Vec<String> = Format::ALL
+            .into_iter()
+            .map(|format| serializer(format).serialize(&person).unwrap())
+            .collect();
+
+        assert_eq!(
+            outputs,
+            [
+                r#"{"name":"John","age":42}"#,
+                "name: John\nage: 42\n",
+                "name = \"John\"\nage = 42\n",
+                "name,age\nJohn,42\n",
+            ]
+        );
+        assert_eq!(json_serializer().serialize(&person), Ok(outputs[0].clone()));
+    }
+
+    #[test]
+    fn toml_errors_become_serialize_errors() {
+        let error = TomlSerializer.serialize(&42).unwrap_err();
+
+        assert!(matches!(
+            error,
+            SerializeError::Toml(TomlError::NotATable { .. })
+        ));
+        assert!(error.to_string().starts_with("cannot write TOML: "));
+        assert!(error::Error::source(&error).is_some());
+    }
+}
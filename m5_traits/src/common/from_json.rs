@@ -0,0 +1,323 @@
+//! `FromJson` for the same types `ToJson` covers.
+
+use super::{
+    parser::{JsonError, JsonValue},
+    FromJson,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::BuildHasher,
+};
+
+/**
+ * Reads the member `name` of an object. A missing member is read as `null`,
+ * so that optional fields may be left out.
+ */
+pub fn field<T: FromJson>(object: &JsonValue, name: &'static str) -> Result<T, JsonError> {
+    let JsonValue::Object(_) = object else {
+        return Err(JsonError::expected("an object", object));
+    };
+    match object.get(name) {
+        Some(value) => T::from_json_value(value).map_err(|e| e.in_field(name)),
+        None => T::from_json_value(&JsonValue::Null).map_err(|_| JsonError::missing(name)),
+    }
+}
+
+fn items(value: &JsonValue) -> Result<&[JsonValue], JsonError> {
+    match value {
+        JsonValue::Array(items) => Ok(items),
+        _ => Err(JsonError::expected("an array", value)),
+    }
+}
+
+fn items_exactly(value: &JsonValue, len: usize) -> Result<&[JsonValue], JsonError> {
+    let items = items(value)?;
+    if items.len() != len {
+        return Err(JsonError::invalid(format!(
+            "expected an array of length {len}, found length {}",
+            items.len()
+        )));
+    }
+    Ok(items)
+}
+
+fn item<T: FromJson>(items: &[JsonValue], index: usize) -> Result<T, JsonError> {
+    T::from_json_value(&items[index]).map_err(|e| e.at_index(index))
+}
+
+fn members(value: &JsonValue) -> Result<&[(String, JsonValue)], JsonError> {
+    match value {
+        JsonValue::Object(members) => Ok(members),
+        _ => Err(JsonError::expected("an object", value)),
+    }
+}
+
+macro_rules! impl_from_json_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl FromJson for $t {
+                fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+                    let JsonValue::Number(n) = value else {
+                        return Err(JsonError::expected(stringify!($t), value));
+                    };
+                    if n.contains(['.', 'e', 'E']) {
+                        return Err(JsonError::invalid(format!("{n} is not an integer")));
+                    }
+                    n.parse().map_err(|_| {
+                        JsonError::invalid(format!("{n} does not fit in {}", stringify!($t)))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_json_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_from_json_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl FromJson for $t {
+                fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+                    match value {
+                        JsonValue::Number(n) => Ok(n.parse().expect("JSON numbers are valid floats")),
+                        _ => Err(JsonError::expected(stringify!($t), value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_json_for_floats!(f32, f64);
+
+impl FromJson for bool {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        match value {
+            JsonValue::Bool(b) => Ok(*b),
+            _ => Err(JsonError::expected("a boolean", value)),
+        }
+    }
+}
+
+impl FromJson for () {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        match value {
+            JsonValue::Null => Ok(()),
+            _ => Err(JsonError::expected("null", value)),
+        }
+    }
+}
+
+impl FromJson for char {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        let s = String::from_json_value(value)?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(JsonError::invalid(format!(
+                "expected a single character, found {s:?}"
+            ))),
+        }
+    }
+}
+
+impl FromJson for String {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        match value {
+            JsonValue::String(s) => Ok(s.clone()),
+            _ => Err(JsonError::expected("a string", value)),
+        }
+    }
+}
+
+impl FromJson for JsonValue {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        Ok(value.clone())
+    }
+}
+
+impl<T: FromJson> FromJson for Box<T> {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        T::from_json_value(value).map(Box::new)
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        match value {
+            JsonValue::Null => Ok(None),
+            value => T::from_json_value(value).map(Some),
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        let items = items(value)?;
+        (0..items.len()).map(|i| item(items, i)).collect()
+    }
+}
+
+impl<T: FromJson, const N: usize> FromJson for [T; N] {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        let items = items_exactly(value, N)?;
+        let items: Vec<T> = (0..N).map(|i| item(items, i)).collect::<Result<_, _>>()?;
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("length was checked")))
+    }
+}
+
+impl<V: FromJson, S: BuildHasher + Default> FromJson for HashMap<String, V, S> {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        members(value)?
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), V::from_json_value(v).map_err(|e| e.in_field(k))?)))
+            .collect()
+    }
+}
+
+impl<V: FromJson> FromJson for BTreeMap<String, V> {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        members(value)?
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), V::from_json_value(v).map_err(|e| e.in_field(k))?)))
+            .collect()
+    }
+}
+
+macro_rules! impl_from_json_for_tuples {
+    ($(($len:literal: $($name:ident $index:tt),+))*) => {
+        $(
+            impl<$($name: FromJson),+> FromJson for ($($name,)+) {
+                fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+                    let items = items_exactly(value, $len)?;
+                    Ok(($(item::<$name>(items, $index)?,)+))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_json_for_tuples! {
+    (1: A 0)
+    (2: A 0, B 1)
+    (3: A 0, B 1, C 2)
+    (4: A 0, B 1, C 2, D 3)
+    (5: A 0, B 1, C 2, D 3, E 4)
+    (6: A 0, B 1, C 2, D 3, E 4, F 5)
+    (7: A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+    (9: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8)
+    (10: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9)
+    (11: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10)
+    (12: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Coord, Person, ToJson};
+    use super::*;
+    use std::fmt::Debug;
+
+    /// Asserts that `value` survives a trip through `ToJson` and `FromJson`.
+    fn assert_round_trip<T: ToJson + FromJson + PartialEq + Debug>(value: T) {
+        assert_eq!(T::from_json(&value.to_json()).unwrap(), value);
+    }
+
+    fn error_of<T: FromJson + Debug>(json: &str) -> String {
+        T::from_json(json).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn should_round_trip_std_types() {
+        assert_round_trip(true);
+        assert_round_trip(());
+        assert_round_trip('🦀');
+        assert_round_trip(i128::MIN);
+        assert_round_trip(u64::MAX);
+        assert_round_trip(0.1f64);
+        assert_round_trip(-1.5e300f64);
+        assert_round_trip(f32::MAX);
+        assert_round_trip("line\nbreak \"quoted\"".to_string());
+        assert_round_trip(Some(Box::new(3)));
+        assert_round_trip(None::<String>);
+        assert_round_trip(vec![vec![1u8], vec![]]);
+        assert_round_trip([(1, 'a'), (2, 'b')]);
+        assert_round_trip((1, "two".to_string(), 3.0, [Some(4)]));
+
+        let map: HashMap<String, Vec<bool>> =
+            [("a".to_string(), vec![true]), ("b".to_string(), vec![])]
+                .into_iter()
+                .collect();
+        assert_round_trip(map);
+        let map: BTreeMap<String, i32> = [("x".to_string(), 1)].into_iter().collect();
+        assert_round_trip(map);
+    }
+
+    #[test]
+    fn should_read_what_serde_json_writes() {
+        let values = vec![(0.1, -2), (1e21, 7), (5e-324, i64::MIN)];
+        let json = serde_json::to_string(&values).unwrap();
+
+        assert_eq!(Vec::<(f64, i64)>::from_json(&json).unwrap(), values);
+    }
+
+    #[test]
+    fn should_read_person_and_coord() {
+        let person = Person::from_json(r#"{"age": 42, "name": "John", "extra": []}"#).unwrap();
+        assert_eq!((person.name.as_str(), person.age), ("John", 42));
+
+        let coord = Coord::from_json(r#"{"x": -1, "y": 2}"#).unwrap();
+        assert_eq!((coord.x, coord.y), (-1, 2));
+    }
+
+    #[test]
+    fn should_reject_age_that_does_not_fit_in_u8() {
+        assert_eq!(
+            error_of::<Person>(r#"{"name": "Methuselah", "age": 969}"#),
+            "at `age`: 969 does not fit in u8"
+        );
+        assert_eq!(
+            error_of::<Person>(r#"{"name": "Unborn", "age": -1}"#),
+            "at `age`: -1 does not fit in u8"
+        );
+        assert_eq!(
+            error_of::<Person>(r#"{"name": "Half", "age": 0.5}"#),
+            "at `age`: 0.5 is not an integer"
+        );
+    }
+
+    #[test]
+    fn should_reject_implausible_person() {
+        assert_eq!(
+            error_of::<Person>(r#"{"name": " ", "age": 42}"#),
+            "at `name`: name must not be empty"
+        );
+        assert_eq!(
+            error_of::<Vec<Person>>(r#"[{"name": "Methuselah", "age": 200}]"#),
+            "at `[0].age`: age 200 is implausible, it must be at most 130"
+        );
+    }
+
+    #[test]
+    fn should_report_path_of_nested_errors() {
+        assert_eq!(
+            error_of::<Vec<Person>>(r#"[{"name": "A", "age": 1}, {"name": 2, "age": 1}]"#),
+            "at `[1].name`: expected a string, found a number"
+        );
+        assert_eq!(
+            error_of::<BTreeMap<String, Coord>>(r#"{"home": {"x": 1}}"#),
+            "at `home`: missing field `y`"
+        );
+        assert_eq!(
+            error_of::<(i32, i32)>("[1, 2, 3]"),
+            "expected an array of length 2, found length 3"
+        );
+        assert_eq!(
+            error_of::<Person>("{\n  \"name\": \"A\",\n  \"age\": 1,\n}"),
+            "expected a string as object key at line 4 column 1"
+        );
+    }
+}
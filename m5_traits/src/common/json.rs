@@ -0,0 +1,692 @@
+//! A hand-written JSON encoder behind `ToJson`.
+//!
+//! The output is the same as `serde_json::to_string`: compact, with strings
+//! escaped the way serde_json does and floats in ryu's shortest notation.
+//! `pretty` lays the JSON out like `serde_json::to_string_pretty`.
+
+use super::ToJson;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, io,
+    str::FromStr,
+};
+
+/// Writes `s` as a quoted JSON string.
+pub fn escape_str(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            c if c < '\u{20}' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Encodes the fields of a struct as a JSON object, in the given order.
+pub fn object(fields: &[(&str, &dyn ToJson)]) -> String {
+    let mut out = String::from("{");
+    for (i, (name, value)) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        escape_str(name, &mut out);
+        out.push(':');
+        out.push_str(&value.to_json());
+    }
+    out.push('}');
+    out
+}
+
+/// JSON that is already encoded, written as is.
+pub struct Raw(pub String);
+
+impl ToJson for Raw {
+    fn to_json(&self) -> String {
+        self.0.clone()
+    }
+}
+
+fn array<'a, T, I>(items: I) -> String
+where
+    T: ToJson + 'a + ?Sized,
+    I: IntoIterator<Item = &'a T>,
+{
+    let items: Vec<String> = items.into_iter().map(ToJson::to_json).collect();
+    format!("[{}]", items.join(","))
+}
+
+fn map<'a, K, V, I>(entries: I) -> String
+where
+    K: AsRef<str> + 'a,
+    V: ToJson + 'a,
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    let mut out = String::from("{");
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        escape_str(key.as_ref(), &mut out);
+        out.push(':');
+        out.push_str(&value.to_json());
+    }
+    out.push('}');
+    out
+}
+
+fn write_array<'a, T, I, W>(items: I, writer: &mut W) -> io::Result<()>
+where
+    T: ToJson + 'a,
+    I: IntoIterator<Item = &'a T>,
+    W: io::Write,
+{
+    writer.write_all(b"[")?;
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        item.write_json(writer)?;
+    }
+    writer.write_all(b"]")
+}
+
+fn write_map<'a, K, V, I, W>(entries: I, writer: &mut W) -> io::Result<()>
+where
+    K: AsRef<str> + 'a,
+    V: ToJson + 'a,
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+    W: io::Write,
+{
+    writer.write_all(b"{")?;
+    let mut key = String::new();
+    for (i, (k, value)) in entries.into_iter().enumerate() {
+        key.clear();
+        if i > 0 {
+            key.push(',');
+        }
+        escape_str(k.as_ref(), &mut key);
+        key.push(':');
+        writer.write_all(key.as_bytes())?;
+        value.write_json(writer)?;
+    }
+    writer.write_all(b"}")
+}
+
+/**
+ * Lays out compact JSON over several lines, with `indent` spaces per level of
+ * nesting, like `serde_json::to_string_pretty`.
+ *
+ * Works on the text in one pass, so there is no limit on how deep the JSON
+ * nests. Whitespace outside of strings is dropped; invalid JSON is laid out
+ * as well as it goes.
+ */
+pub fn pretty(json: &str, indent: usize) -> String {
+    let newline = |out: &mut String, depth: usize| {
+        out.push('\n');
+        out.extend(std::iter::repeat_n(' ', indent * depth));
+    };
+
+    let mut out = String::with_capacity(json.len());
+    let mut depth = 0;
+    let mut chars = json.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                out.push('"');
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    match c {
+                        '\\' => out.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '[' | '{' => {
+                let close = if c == '[' { ']' } else { '}' };
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                out.push(c);
+                if chars.next_if_eq(&close).is_some() {
+                    out.push(close);
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            ']' | '}' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut out, depth);
+                out.push(c);
+            }
+            ',' => {
+                out.push(',');
+                newline(&mut out, depth);
+            }
+            ':' => out.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// The float types, with the thresholds ryu uses to switch to scientific notation.
+trait Float: Copy + PartialEq + fmt::LowerExp + FromStr {
+    /// Up to this many digits before the point are written out in full.
+    const MAX_POINT: i32;
+    /// Down to this many zeros after the point are written out in full.
+    const MIN_POINT: i32;
+}
+
+impl Float for f64 {
+    const MAX_POINT: i32 = 16;
+    const MIN_POINT: i32 = -5;
+}
+
+impl Float for f32 {
+    const MAX_POINT: i32 = 13;
+    const MIN_POINT: i32 = -6;
+}
+
+/// Splits `{:e}` output like `1.2345e-7` into its digits and exponent.
+fn split_exp(exp: &str) -> (String, i32) {
+    let (mantissa, e) = exp.split_once('e').expect("`{:e}` always has an exponent");
+    let e = e.parse().expect("`{:e}` exponent is an integer");
+    (mantissa.replace('.', ""), e)
+}
+
+/**
+ * Formats a finite float like ryu does, starting from the shortest
+ * round-trip digits that `{:e}` produces.
+ *
+ * Numbers whose decimal point falls close enough to the digits are written
+ * out in full (`12340000.0`, `0.001234`), everything else in scientific
+ * notation (`1.234e33`).
+ */
+fn format_float<F: Float>(value: F) -> String {
+    let shortest = format!("{value:e}");
+    let (sign, exp) = match shortest.strip_prefix('-') {
+        Some(exp) => ("-", exp),
+        None => ("", shortest.as_str()),
+    };
+    let (mut digits, e) = split_exp(exp);
+    if let Some(even) = round_half_to_even(value, sign, &digits, e) {
+        digits = even;
+    }
+    let length = digits.len() as i32;
+
+    // The value is `0.digits * 10^point`, or `digits * 10^k`.
+    let point = e + 1;
+    let k = point - length;
+
+    let formatted = if 0 <= k && point <= F::MAX_POINT {
+        format!("{digits}{}.0", "0".repeat(k as usize))
+    } else if 0 < point && point <= F::MAX_POINT {
+        let (int, frac) = digits.split_at(point as usize);
+        format!("{int}.{frac}")
+    } else if F::MIN_POINT < point && point <= 0 {
+        format!("0.{}{digits}", "0".repeat(-point as usize))
+    } else if length == 1 {
+        format!("{digits}e{}", point - 1)
+    } else {
+        format!("{}.{}e{}", &digits[..1], &digits[1..], point - 1)
+    };
+    format!("{sign}{formatted}")
+}
+
+/**
+ * `{:e}` rounds a float that lies exactly halfway between two shortest
+ * candidates up, ryu rounds it to the even one. Returns the even digits in
+ * that case.
+ */
+fn round_half_to_even<F: Float>(value: F, sign: &str, digits: &str, e: i32) -> Option<String> {
+    let (init, last) = digits.split_at(digits.len() - 1);
+    let last: u8 = last.parse().ok()?;
+    if last.is_multiple_of(2) {
+        return None;
+    }
+    let lower = format!("{init}{}", last - 1);
+
+    // Exact decimal expansion; no float has more than 767 significant digits
+    let exact = format!("{value:.800e}");
+    let (exact_digits, exact_e) = split_exp(exact.trim_start_matches('-'));
+    let halfway = match format!("{lower}5").trim_start_matches('0') {
+        halfway if halfway.len() == lower.len() + 1 => (halfway.to_string(), e),
+        halfway => (halfway.to_string(), e - 1),
+    };
+    if (exact_digits.trim_end_matches('0'), exact_e) != (halfway.0.as_str(), halfway.1) {
+        return None;
+    }
+
+    let lower_value = format!("{sign}0.{lower}e{}", e + 1).parse::<F>().ok()?;
+    (lower_value == value).then_some(lower)
+}
+
+impl ToJson for f64 {
+    fn to_json(&self) -> String {
+        if self.is_finite() {
+            format_float(*self)
+        } else {
+            "null".to_string()
+        }
+    }
+}
+
+impl ToJson for f32 {
+    fn to_json(&self) -> String {
+        if self.is_finite() {
+            format_float(*self)
+        } else {
+            "null".to_string()
+        }
+    }
+}
+
+macro_rules! impl_to_json_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl ToJson for $t {
+                fn to_json(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_json_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl ToJson for bool {
+    fn to_json(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToJson for () {
+    fn to_json(&self) -> String {
+        "null".to_string()
+    }
+}
+
+impl ToJson for char {
+    fn to_json(&self) -> String {
+        let mut out = String::new();
+        escape_str(self.encode_utf8(&mut [0; 4]), &mut out);
+        out
+    }
+}
+
+impl ToJson for str {
+    fn to_json(&self) -> String {
+        let mut out = String::with_capacity(self.len() + 2);
+        escape_str(self, &mut out);
+        out
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> String {
+        self.as_str().to_json()
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn to_json(&self) -> String {
+        (**self).to_json()
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for &mut T {
+    fn to_json(&self) -> String {
+        (**self).to_json()
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for Box<T> {
+    fn to_json(&self) -> String {
+        (**self).to_json()
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> String {
+        match self {
+            Some(value) => value.to_json(),
+            None => "null".to_string(),
+        }
+    }
+
+    fn write_json<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Some(value) => value.write_json(writer),
+            None => writer.write_all(b"null"),
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn to_json(&self) -> String {
+        array(self)
+    }
+}
+
+impl<T: ToJson, const N: usize> ToJson for [T; N] {
+    fn to_json(&self) -> String {
+        array(self)
+    }
+
+    fn write_json<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_array(self, writer)
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> String {
+        array(self)
+    }
+
+    fn write_json<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_array(self, writer)
+    }
+}
+
+impl<K: AsRef<str>, V: ToJson, S> ToJson for HashMap<K, V, S> {
+    fn to_json(&self) -> String {
+        map(self)
+    }
+
+    fn write_json<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_map(self, writer)
+    }
+}
+
+impl<K: AsRef<str>, V: ToJson> ToJson for BTreeMap<K, V> {
+    fn to_json(&self) -> String {
+        map(self)
+    }
+
+    fn write_json<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_map(self, writer)
+    }
+}
+
+macro_rules! impl_to_json_for_tuples {
+    ($(($($name:ident),+))*) => {
+        $(
+            impl<$($name: ToJson),+> ToJson for ($($name,)+) {
+                #[allow(non_snake_case)]
+                fn to_json(&self) -> String {
+                    let ($($name,)+) = self;
+                    let items = [$($name.to_json()),+];
+                    format!("[{}]", items.join(","))
+                }
+            }
+        )*
+    };
+}
+
+impl_to_json_for_tuples! {
+    (A)
+    (A, B)
+    (A, B, C)
+    (A, B, C, D)
+    (A, B, C, D, E)
+    (A, B, C, D, E, F)
+    (A, B, C, D, E, F, G)
+    (A, B, C, D, E, F, G, H)
+    (A, B, C, D, E, F, G, H, I)
+    (A, B, C, D, E, F, G, H, I, J)
+    (A, B, C, D, E, F, G, H, I, J, K)
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Coord, Person};
+    use super::*;
+    use serde::Serialize;
+
+    /// Asserts that `ToJson` and serde_json agree on `value`.
+    fn assert_same<T: ToJson + Serialize + ?Sized>(value: &T) {
+        assert_eq!(value.to_json(), serde_json::to_string(value).unwrap());
+    }
+
+    /// A xorshift generator, enough to pick arbitrary bit patterns.
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn primitives_match_serde_json() {
+        assert_same(&true);
+        assert_same(&());
+        assert_same(&'x');
+        assert_same(&i8::MIN);
+        assert_same(&u64::MAX);
+        assert_same(&i128::MIN);
+        assert_same(&u128::MAX);
+        assert_same(&-42isize);
+    }
+
+    #[test]
+    fn strings_are_escaped_like_serde_json() {
+        assert_same("plain");
+        assert_same(&"quote \" backslash \\ slash /".to_string());
+        assert_same("\n\r\t\u{08}\u{0c}");
+        assert_same("\u{0}\u{1}\u{1f}\u{7f}");
+        assert_same("유니코드 🦀");
+        assert_same(&'\n');
+        assert_same(&'"');
+
+        let all_ascii: String = (0u8..128).map(char::from).collect();
+        assert_same(&all_ascii);
+    }
+
+    #[test]
+    fn floats_match_serde_json() {
+        let f64s = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.5,
+            0.1,
+            0.3,
+            1e15,
+            1e16,
+            1e17,
+            1.5e16,
+            123456789012345680.0,
+            1e-5,
+            1e-4,
+            1.5e-5,
+            0.000123,
+            1e-7,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            std::f64::consts::PI,
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+        for value in f64s {
+            assert_same(&value);
+        }
+
+        let f32s = [
+            0.0,
+            1.0,
+            0.1,
+            1e12,
+            1e13,
+            1.5e13,
+            1e-6,
+            1e-5,
+            f32::MAX,
+            f32::NAN,
+        ];
+        for value in f32s {
+            assert_same(&value);
+        }
+
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        assert_same(&3065919.2f32);
+        for _ in 0..10_000 {
+            let bits = xorshift(&mut state);
+            assert_same(&f64::from_bits(bits));
+            assert_same(&f32::from_bits(bits as u32));
+        }
+    }
+
+    #[test]
+    fn collections_match_serde_json() {
+        assert_same(&Some(1));
+        assert_same(&None::<i32>);
+        assert_same(&vec![1, 2, 3]);
+        assert_same(&Vec::<String>::new());
+        assert_same(&[1.5, 2.0][..]);
+        assert_same(&["a", "b"]);
+        assert_same(&[[0u8; 0]; 2]);
+        assert_same(&(1, "two", 3.0, Some('4'), vec![5]));
+        assert_same(&vec![Some(vec![(1, true)]), None]);
+
+        let hash_map: HashMap<String, Vec<i32>> = [
+            ("one".to_string(), vec![1]),
+            ("two".to_string(), vec![2, 2]),
+            ("\"quoted\"".to_string(), vec![]),
+        ]
+        .into_iter()
+        .collect();
+        assert_same(&hash_map);
+
+        let btree_map: BTreeMap<&str, Option<f64>> =
+            [("b", Some(0.5)), ("a", None)].into_iter().collect();
+        assert_same(&btree_map);
+    }
+
+    #[test]
+    fn person_and_coord_match_serde_json() {
+        assert_same(&Person::new("John \"Johnny\" Doe", 42));
+        assert_same(&Coord::new(-1, i32::MAX));
+        assert_same(&vec![Coord::new(1, 2), Coord::new(3, 4)]);
+
+        let people: BTreeMap<String, Person> = [("john".to_string(), Person::new("John", 42))]
+            .into_iter()
+            .collect();
+        assert_same(&people);
+    }
+
+    /// Pretty-prints `value` with serde_json, indenting by `indent` spaces.
+    fn serde_pretty<T: Serialize + ?Sized>(value: &T, indent: usize) -> String {
+        let indent = " ".repeat(indent);
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+        let mut out = Vec::new();
+        let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+        value.serialize(&mut serializer).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn pretty_output_matches_serde_json() {
+        let people = vec![Person::new("John", 42), Person::new("Jane \"J\"", 7)];
+        let empty: (Vec<u8>, BTreeMap<&str, f64>, Option<()>) = (vec![], BTreeMap::new(), None);
+        let full = (vec![1, 2], [("half", 0.5)].into(), Some(()));
+        let nested = BTreeMap::from([("a", empty), ("b", full)]);
+
+        for indent in [0, 2, 4] {
+            assert_eq!(people.to_json_pretty(indent), serde_pretty(&people, indent));
+            assert_eq!(nested.to_json_pretty(indent), serde_pretty(&nested, indent));
+        }
+        assert_eq!(42.to_json_pretty(2), "42");
+        assert_eq!(
+            Coord::new(1, 2).to_json_pretty(2),
+            "{\n  \"x\": 1,\n  \"y\": 2\n}"
+        );
+        // Strings may hold brackets, commas and escaped quotes.
+        assert_eq!(
+            pretty("{\"a,b\": [\"[\\\"]\", { }]}", 2),
+            serde_pretty(&serde_json::json!({"a,b": ["[\"]", {}]}), 2)
+        );
+    }
+
+    /// JSON nested `depth` levels deep, like `[[[1]]]`.
+    struct Deep(usize);
+
+    impl ToJson for Deep {
+        fn to_json(&self) -> String {
+            format!("{}1{}", "[".repeat(self.0), "]".repeat(self.0))
+        }
+    }
+
+    #[test]
+    fn pretty_output_has_no_depth_limit() {
+        let depth = 1000;
+        let pretty = Deep(depth).to_json_pretty(1);
+        let lines: Vec<&str> = pretty.lines().collect();
+
+        assert_eq!(lines.len(), 2 * depth + 1);
+        assert_eq!(lines[1], " [");
+        assert_eq!(lines[depth], format!("{}1", " ".repeat(depth)));
+        assert_eq!(lines[2 * depth], "]");
+    }
+
+    /// A writer that records the size of its largest write.
+    #[derive(Default)]
+    struct Recorder {
+        written: Vec<u8>,
+        largest_write: usize,
+    }
+
+    impl io::Write for Recorder {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.largest_write = self.largest_write.max(buf.len());
+            self.written.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_json_streams_collections() {
+        let people: Vec<Person> = (0..1000)
+            .map(|i| Person::new(&format!("#{i}"), 1))
+            .collect();
+        let mut recorder = Recorder::default();
+        people.write_json(&mut recorder).unwrap();
+
+        assert_eq!(
+            String::from_utf8(recorder.written).unwrap(),
+            people.to_json()
+        );
+        assert!(recorder.largest_write <= Person::new("#999", 1).to_json().len());
+
+        let mut out = Vec::new();
+        let map: HashMap<String, Option<[i32; 2]>> =
+            [("a".to_string(), Some([1, 2])), ("\n".to_string(), None)].into();
+        map.write_json(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), map.to_json());
+    }
+
+    #[test]
+    fn write_json_reports_write_errors() {
+        let mut full = [0u8; 10];
+        let error = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
+            .write_json(&mut &mut full[..])
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+    }
+}
@@ -0,0 +1,235 @@
+#![allow(unused)]
+
+pub mod coord;
+pub mod csv;
+pub mod document;
+pub mod format;
+pub mod from_json;
+pub mod json;
+pub mod parser;
+pub mod person;
+pub mod toml;
+pub mod yaml;
+
+pub use m5_traits_derive::ToJson;
+use parser::{JsonError, JsonValue};
+use serde::{Deserialize, Serialize};
+use std::io;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToJson)]
+pub struct Person {
+    pub name: String,
+    pub age: u8,
+}
+
+impl Person {
+    /// Takes any name and age; see `try_new` for a checked version.
+    pub fn new(name: &str, age: u8) -> Self {
+        Self {
+            name: name.to_owned(),
+            age,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, ToJson)]
+pub struct Coord {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coord {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+pub trait ToJson {
+    fn to_json(&self) -> String;
+
+    /**
+     * The same JSON as `to_json`, laid out over several lines with `indent`
+     * spaces per level of nesting, like `serde_json::to_string_pretty`.
+     */
+    fn to_json_pretty(&self, indent: usize) -> String {
+        json::pretty(&self.to_json(), indent)
+    }
+
+    /**
+     * Writes the same JSON as `to_json` to `writer`. Collections write their
+     * elements one at a time, so a large `Vec` is never held in memory as a
+     * single string; wrap unbuffered writers such as files in a `BufWriter`.
+     */
+    fn write_json<W: io::Write>(&self, writer: &mut W) -> io::Result<()>
+    where
+        Self: Sized,
+    {
+        writer.write_all(self.to_json().as_bytes())
+    }
+}
+
+pub trait ToYaml {
+    fn to_yaml_node(&self) -> yaml::Node;
+
+    fn to_yaml(&self) -> String {
+        yaml::render(&self.to_yaml_node())
+    }
+}
+
+/**
+ * Unlike JSON and YAML, TOML cannot write every value: a document must be a
+ * table, integers must fit in an `i64` and arrays cannot hold `None`.
+ */
+pub trait ToToml {
+    fn to_toml_value(&self) -> Result<toml::Value, toml::TomlError>;
+
+    fn to_toml(&self) -> Result<String, toml::TomlError> {
+        toml::render(&self.to_toml_value()?)
+    }
+}
+
+/// A CSV table, for collections of `csv::Record`s such as `Vec<Person>`.
+pub trait ToCsv {
+    fn to_csv(&self) -> String;
+}
+
+pub trait FromJson: Sized {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError>;
+
+    fn from_json(json: &str) -> Result<Self, JsonError> {
+        Self::from_json_value(&parser::parse(json)?)
+    }
+}
+
+impl ToYaml for Person {
+    fn to_yaml_node(&self) -> yaml::Node {
+        yaml::mapping(&[("name", &self.name), ("age", &self.age)])
+    }
+}
+
+impl ToYaml for Coord {
+    fn to_yaml_node(&self) -> yaml::Node {
+        yaml::mapping(&[("x", &self.x), ("y", &self.y)])
+    }
+}
+
+impl ToToml for Person {
+    fn to_toml_value(&self) -> Result<toml::Value, toml::TomlError> {
+        toml::table(&[("name", &self.name), ("age", &self.age)])
+    }
+}
+
+impl ToToml for Coord {
+    fn to_toml_value(&self) -> Result<toml::Value, toml::TomlError> {
+        toml::table(&[("x", &self.x), ("y", &self.y)])
+    }
+}
+
+impl FromJson for Person {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        let name: String = from_json::field(value, "name")?;
+        let age = from_json::field(value, "age")?;
+        Person::try_new(&name, age)
+            .map_err(|e| JsonError::invalid(e.to_string()).in_field(e.field()))
+    }
+}
+
+impl FromJson for Coord {
+    fn from_json_value(value: &JsonValue) -> Result<Self, JsonError> {
+        Ok(Coord {
+            x: from_json::field(value, "x")?,
+            y: from_json::field(value, "y")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    /// Asserts that the derived `ToJson` agrees with serde_json on `value`.
+    fn assert_same<T: ToJson + Serialize>(value: &T) {
+        assert_eq!(value.to_json(), serde_json::to_string(value).unwrap());
+    }
+
+    #[derive(Serialize, ToJson)]
+    struct Account {
+        #[json(rename = "userName")]
+        #[serde(rename = "userName")]
+        user_name: String,
+        #[json(skip)]
+        #[serde(skip)]
+        password: String,
+        roles: Vec<Role>,
+        settings: BTreeMap<String, Option<bool>>,
+    }
+
+    #[derive(Serialize, ToJson)]
+    enum Role {
+        Guest,
+        #[json(rename = "admin")]
+        #[serde(rename = "admin")]
+        Admin,
+        Member(u32),
+        Moderator(String, Vec<u8>),
+        Owner {
+            since: u16,
+            coord: Coord,
+        },
+    }
+
+    #[derive(Serialize, ToJson)]
+    struct Meters(f64);
+
+    #[derive(Serialize, ToJson)]
+    struct Pair<T>(T, T);
+
+    #[derive(Serialize, ToJson)]
+    struct Nothing;
+
+    #[derive(Serialize, ToJson)]
+    struct Wrapper<'a, T> {
+        inner: &'a T,
+    }
+
+    #[test]
+    fn derived_structs_match_serde_json() {
+        assert_same(&Person::new("John", 42));
+        assert_same(&Coord::new(1, 2));
+        assert_same(&Meters(1.5));
+        assert_same(&Pair("a", "b"));
+        assert_same(&Nothing);
+        assert_same(&Wrapper { inner: &[1, 2] });
+
+        let account = Account {
+            user_name: "john".to_string(),
+            password: "hunter2".to_string(),
+            roles: vec![],
+            settings: [("dark".to_string(), Some(true)), ("beta".to_string(), None)]
+                .into_iter()
+                .collect(),
+        };
+        assert_same(&account);
+        assert!(!account.to_json().contains("hunter2"));
+    }
+
+    #[test]
+    fn derived_enums_are_externally_tagged() {
+        let roles = vec![
+            Role::Guest,
+            Role::Admin,
+            Role::Member(7),
+            Role::Moderator("\"mod\"".to_string(), vec![1, 2]),
+            Role::Owner {
+                since: 2020,
+                coord: Coord::new(-1, 1),
+            },
+        ];
+        assert_same(&roles);
+        assert_eq!(
+            roles.to_json(),
+            r#"["Guest","admin",{"Member":7},{"Moderator":["\"mod\"",[1,2]]},{"Owner":{"since":2020,"coord":{"x":-1,"y":1}}}]"#
+        );
+    }
+}
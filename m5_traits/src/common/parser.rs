@@ -0,0 +1,518 @@
+//! A recursive-descent JSON parser producing a `JsonValue` tree.
+
+use super::ToJson;
+use std::{error, fmt, iter::Peekable, str::Chars};
+
+/// How deeply arrays and objects may be nested, like serde_json.
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    /// The number as written, so that it can be read into any numeric type
+    /// without losing precision.
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    /// Members in the order they appear in the document.
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Describes the kind of value, for error messages.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            JsonValue::Null => "null",
+            JsonValue::Bool(_) => "a boolean",
+            JsonValue::Number(_) => "a number",
+            JsonValue::String(_) => "a string",
+            JsonValue::Array(_) => "an array",
+            JsonValue::Object(_) => "an object",
+        }
+    }
+
+    /// Looks up a member of an object; the last one wins if a key repeats.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(members) => {
+                members.iter().rev().find(|(k, _)| k == key).map(|(_, v)| v)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ToJson for JsonValue {
+    fn to_json(&self) -> String {
+        match self {
+            JsonValue::Null => "null".to_string(),
+            JsonValue::Bool(b) => b.to_json(),
+            JsonValue::Number(n) => n.clone(),
+            JsonValue::String(s) => s.to_json(),
+            JsonValue::Array(items) => items.to_json(),
+            JsonValue::Object(members) => {
+                let fields: Vec<(&str, &dyn ToJson)> = members
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v as &dyn ToJson))
+                    .collect();
+                super::json::object(&fields)
+            }
+        }
+    }
+}
+
+/// Why a document could not be read into a value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonError {
+    /// The document is not well-formed JSON.
+    Syntax {
+        message: String,
+        line: usize,
+        column: usize,
+    },
+    /// The JSON at `path` is of the wrong kind, e.g. a string for a number.
+    Type {
+        path: String,
+        expected: String,
+        found: &'static str,
+    },
+    /// The JSON at `path` has the right kind but an unacceptable value.
+    Invalid {
+        path: String,
+        message: String,
+    },
+    MissingField {
+        path: String,
+        field: &'static str,
+    },
+}
+
+impl JsonError {
+    pub fn expected(expected: impl Into<String>, found: &JsonValue) -> Self {
+        JsonError::Type {
+            path: String::new(),
+            expected: expected.into(),
+            found: found.kind(),
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        JsonError::Invalid {
+            path: String::new(),
+            message: message.into(),
+        }
+    }
+
+    pub fn missing(field: &'static str) -> Self {
+        JsonError::MissingField {
+            path: String::new(),
+            field,
+        }
+    }
+
+    /// Marks the error as coming from the member `name` of an object.
+    pub fn in_field(self, name: &str) -> Self {
+        self.prefix_path(|path| match path.chars().next() {
+            None | Some('[') => format!("{name}{path}"),
+            _ => format!("{name}.{path}"),
+        })
+    }
+
+    /// Marks the error as coming from the item at `index` of an array.
+    pub fn at_index(self, index: usize) -> Self {
+        self.prefix_path(|path| match path.chars().next() {
+            None | Some('[') => format!("[{index}]{path}"),
+            _ => format!("[{index}].{path}"),
+        })
+    }
+
+    fn prefix_path(mut self, prefix: impl FnOnce(&str) -> String) -> Self {
+        match &mut self {
+            JsonError::Syntax { .. } => {}
+            JsonError::Type { path, .. }
+            | JsonError::Invalid { path, .. }
+            | JsonError::MissingField { path, .. } => *path = prefix(path),
+        }
+        self
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let at = |path: &str| {
+            if path.is_empty() {
+                String::new()
+            } else {
+                format!("at `{path}`: ")
+            }
+        };
+        match self {
+            JsonError::Syntax {
+                message,
+                line,
+                column,
+            } => write!(f, "{message} at line {line} column {column}"),
+            JsonError::Type {
+                path,
+                expected,
+                found,
+            } => write!(f, "{}expected {expected}, found {found}", at(path)),
+            JsonError::Invalid { path, message } => write!(f, "{}{message}", at(path)),
+            JsonError::MissingField { path, field } => {
+                write!(f, "{}missing field `{field}`", at(path))
+            }
+        }
+    }
+}
+
+impl error::Error for JsonError {}
+
+/// Parses a complete JSON document.
+pub fn parse(json: &str) -> Result<JsonValue, JsonError> {
+    let mut parser = Parser {
+        chars: json.chars().peekable(),
+        line: 1,
+        column: 1,
+        depth: 0,
+    };
+
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    match parser.chars.peek() {
+        None => Ok(value),
+        Some(_) => Err(parser.error("trailing characters")),
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    /// Position of the next character, both 1-based.
+    line: usize,
+    column: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Into<String>) -> JsonError {
+        JsonError::Syntax {
+            message: message.into(),
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ' | '\t' | '\n' | '\r') = self.chars.peek() {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        match self.chars.peek() {
+            Some(&c) if c == expected => {
+                self.next();
+                Ok(())
+            }
+            Some(&c) => Err(self.error(format!("expected `{expected}`, found `{c}`"))),
+            None => Err(self.error(format!("expected `{expected}`, found end of input"))),
+        }
+    }
+
+    fn value(&mut self) -> Result<JsonValue, JsonError> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('n') => self.literal("null", JsonValue::Null),
+            Some('t') => self.literal("true", JsonValue::Bool(true)),
+            Some('f') => self.literal("false", JsonValue::Bool(false)),
+            Some('"') => self.string().map(JsonValue::String),
+            Some('-' | '0'..='9') => self.number().map(JsonValue::Number),
+            Some('[') => self.nested(Self::array),
+            Some('{') => self.nested(Self::object),
+            Some(&c) => Err(self.error(format!("expected value, found `{c}`"))),
+            None => Err(self.error("expected value, found end of input")),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: JsonValue) -> Result<JsonValue, JsonError> {
+        for expected in word.chars() {
+            if self.chars.peek() != Some(&expected) {
+                return Err(self.error(format!("expected `{word}`")));
+            }
+            self.next();
+        }
+        Ok(value)
+    }
+
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<JsonValue, JsonError>,
+    ) -> Result<JsonValue, JsonError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("recursion limit exceeded"));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn array(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&']') {
+            self.next();
+            return Ok(JsonValue::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+            if self.separator(']')? {
+                return Ok(JsonValue::Array(items));
+            }
+        }
+    }
+
+    /// Consumes the `,` between items, or `close`; returns whether it was `close`.
+    fn separator(&mut self, close: char) -> Result<bool, JsonError> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some(',') => {
+                self.next();
+                Ok(false)
+            }
+            Some(&c) if c == close => {
+                self.next();
+                Ok(true)
+            }
+            _ => Err(self.error(format!("expected `,` or `{close}`"))),
+        }
+    }
+
+    fn object(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect('{')?;
+        let mut members = Vec::new();
+
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'}') {
+            self.next();
+            return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.chars.peek() != Some(&'"') {
+                return Err(self.error("expected a string as object key"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            members.push((key, self.value()?));
+            if self.separator('}')? {
+                return Ok(JsonValue::Object(members));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.expect('"')?;
+        let mut s = String::new();
+
+        loop {
+            if let Some('\u{0}'..='\u{1f}') = self.chars.peek() {
+                return Err(self.error("control character in string"));
+            }
+            match self.next() {
+                Some('"') => return Ok(s),
+                Some('\\') => s.push(self.escape()?),
+                Some(c) => s.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, JsonError> {
+        let c = match self.next() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{08}',
+            Some('f') => '\u{0c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => return self.unicode_escape(),
+            _ => return Err(self.error("invalid escape")),
+        };
+        Ok(c)
+    }
+
+    /// Reads the `XXXX` of `\uXXXX`, combining surrogate pairs.
+    fn unicode_escape(&mut self) -> Result<char, JsonError> {
+        let high = self.hex4()?;
+        let code = match high {
+            0xD800..=0xDBFF => {
+                if self.next() != Some('\\') || self.next() != Some('u') {
+                    return Err(self.error("unpaired surrogate"));
+                }
+                let low = self.hex4()?;
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err(self.error("unpaired surrogate"));
+                }
+                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+            }
+            0xDC00..=0xDFFF => return Err(self.error("unpaired surrogate")),
+            code => code,
+        };
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self
+                .next()
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| self.error("invalid unicode escape"))?;
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+
+    /// `-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?`
+    fn number(&mut self) -> Result<String, JsonError> {
+        let mut n = String::new();
+
+        if self.chars.peek() == Some(&'-') {
+            n.extend(self.next());
+        }
+        match self.chars.peek() {
+            Some('0') => n.extend(self.next()),
+            Some('1'..='9') => self.digits(&mut n),
+            _ => return Err(self.error("invalid number")),
+        }
+        if self.chars.peek() == Some(&'.') {
+            n.extend(self.next());
+            self.required_digits(&mut n)?;
+        }
+        if let Some('e' | 'E') = self.chars.peek() {
+            n.extend(self.next());
+            if let Some('+' | '-') = self.chars.peek() {
+                n.extend(self.next());
+            }
+            self.required_digits(&mut n)?;
+        }
+        Ok(n)
+    }
+
+    fn digits(&mut self, n: &mut String) {
+        while let Some('0'..='9') = self.chars.peek() {
+            n.extend(self.next());
+        }
+    }
+
+    fn required_digits(&mut self, n: &mut String) -> Result<(), JsonError> {
+        if !matches!(self.chars.peek(), Some('0'..='9')) {
+            return Err(self.error("invalid number"));
+        }
+        self.digits(n);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn syntax_error_at(json: &str) -> (usize, usize) {
+        match parse(json) {
+            Err(JsonError::Syntax { line, column, .. }) => (line, column),
+            other => panic!("expected a syntax error for {json:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn should_parse_nested_documents() {
+        let value = parse(r#" { "a": [1, -2.5e3, true, null], "b": {"c": "d"} } "#).unwrap();
+
+        assert_eq!(
+            value,
+            JsonValue::Object(vec![
+                (
+                    "a".to_string(),
+                    JsonValue::Array(vec![
+                        JsonValue::Number("1".to_string()),
+                        JsonValue::Number("-2.5e3".to_string()),
+                        JsonValue::Bool(true),
+                        JsonValue::Null,
+                    ])
+                ),
+                (
+                    "b".to_string(),
+                    JsonValue::Object(vec![("c".to_string(), JsonValue::String("d".to_string()))])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn should_decode_escapes_like_serde_json() {
+        let json = r#""\" \\ \/ \b \f \n \r \t \u00e9 \ud83e\udd80""#;
+
+        let value = parse(json).unwrap();
+
+        let expected: String = serde_json::from_str(json).unwrap();
+        assert_eq!(value, JsonValue::String(expected));
+    }
+
+    #[test]
+    fn should_report_line_and_column_of_syntax_errors() {
+        assert_eq!(syntax_error_at("{\n  \"a\": 1,\n  \"b\" 2\n}"), (3, 7));
+        assert_eq!(syntax_error_at("[1, 2"), (1, 6));
+        assert_eq!(syntax_error_at("[01]"), (1, 3));
+        assert_eq!(syntax_error_at("-"), (1, 2));
+        assert_eq!(syntax_error_at("1."), (1, 3));
+        assert_eq!(syntax_error_at("\"tab\there\""), (1, 5));
+        assert_eq!(syntax_error_at("\"\\ud83e\""), (1, 9));
+        assert_eq!(syntax_error_at("tru"), (1, 4));
+        assert_eq!(syntax_error_at("{} {}"), (1, 4));
+        assert_eq!(syntax_error_at(""), (1, 1));
+    }
+
+    #[test]
+    fn should_limit_nesting() {
+        assert!(parse(&"[".repeat(MAX_DEPTH + 1)).is_err());
+        assert!(parse(&format!(
+            "{}{}",
+            "[".repeat(MAX_DEPTH),
+            "]".repeat(MAX_DEPTH)
+        ))
+        .is_ok());
+    }
+
+    #[test]
+    fn should_describe_errors_with_path() {
+        let error = JsonError::invalid("300 does not fit in u8")
+            .in_field("age")
+            .at_index(1)
+            .in_field("people");
+
+        assert_eq!(
+            error.to_string(),
+            "at `people[1].age`: 300 does not fit in u8"
+        );
+    }
+}
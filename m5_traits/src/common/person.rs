@@ -0,0 +1,98 @@
+//! Checked construction of `Person`.
+
+use super::Person;
+use std::{error, fmt};
+
+/// Why `Person::try_new` turned down a name or an age.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PersonError {
+    /// The name is empty or only whitespace.
+    EmptyName,
+    /// The name has more than `Person::MAX_NAME_LEN` characters.
+    NameTooLong { len: usize },
+    /// Nobody has been recorded living longer than `Person::MAX_AGE`.
+    ImplausibleAge(u8),
+}
+
+impl PersonError {
+    /// The field that failed validation.
+    pub fn field(&self) -> &'static str {
+        match self {
+            PersonError::EmptyName | PersonError::NameTooLong { .. } => "name",
+            PersonError::ImplausibleAge(_) => "age",
+        }
+    }
+}
+
+impl fmt::Display for PersonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersonError::EmptyName => write!(f, "name must not be empty"),
+            PersonError::NameTooLong { len } => write!(
+                f,
+                "name has {len} characters, at most {} are allowed",
+                Person::MAX_NAME_LEN
+            ),
+            PersonError::ImplausibleAge(age) => write!(
+                f,
+                "age {age} is implausible, it must be at most {}",
+                Person::MAX_AGE
+            ),
+        }
+    }
+}
+
+impl error::Error for PersonError {}
+
+impl Person {
+    /// Longest accepted name, in characters.
+    pub const MAX_NAME_LEN: usize = 100;
+    pub const MAX_AGE: u8 = 130;
+
+    /// Like `new`, but rejects names and ages no real person has.
+    pub fn try_new(name: &str, age: u8) -> Result<Self, PersonError> {
+        let len = name.chars().count();
+        if name.trim().is_empty() {
+            Err(PersonError::EmptyName)
+        } else if len > Self::MAX_NAME_LEN {
+            Err(PersonError::NameTooLong { len })
+        } else if age > Self::MAX_AGE {
+            Err(PersonError::ImplausibleAge(age))
+        } else {
+            Ok(Self::new(name, age))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_accept_plausible_people() {
+        let person = Person::try_new("John", 42).unwrap();
+        assert_eq!((person.name.as_str(), person.age), ("John", 42));
+
+        assert!(Person::try_new("Newborn", 0).is_ok());
+        assert!(Person::try_new("Old", Person::MAX_AGE).is_ok());
+        assert!(Person::try_new(&"é".repeat(Person::MAX_NAME_LEN), 1).is_ok());
+    }
+
+    #[test]
+    fn should_reject_implausible_people() {
+        assert_eq!(Person::try_new("", 42), Err(PersonError::EmptyName));
+        assert_eq!(Person::try_new(" \t", 42), Err(PersonError::EmptyName));
+        assert_eq!(
+            Person::try_new(&"x".repeat(101), 42),
+            Err(PersonError::NameTooLong { len: 101 })
+        );
+        assert_eq!(
+            Person::try_new("Methuselah", 200),
+            Err(PersonError::ImplausibleAge(200))
+        );
+        assert_eq!(
+            Person::try_new("Methuselah", 200).unwrap_err().to_string(),
+            "age 200 is implausible, it must be at most 130"
+        );
+    }
+}
@@ -0,0 +1,428 @@
+//! A hand-written TOML encoder behind `ToToml`.
+//!
+//! A TOML document is always a table. Its plain values are written first as
+//! `key = value` lines, then each nested table under a `[header]` and each
+//! array of tables as a run of `[[header]]` sections. Tables nested anywhere
+//! else, e.g. in an array of numbers and tables, are written inline.
+
+use super::{ToJson, ToToml};
+use std::{
+    collections::{BTreeMap, HashMap},
+    error, fmt,
+};
+
+/// A TOML value, ready to be laid out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// No value at all, like `None`. TOML has no null, so it is left out of
+    /// tables and cannot appear in arrays.
+    Absent,
+    /// A string, number or boolean as it is written, quotes included.
+    Scalar(String),
+    Array(Vec<Value>),
+    /// Entries in the order they are written.
+    Table(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Describes the kind of value, for error messages.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Value::Absent => "nothing",
+            Value::Scalar(_) => "a scalar",
+            Value::Array(_) => "an array",
+            Value::Table(_) => "a table",
+        }
+    }
+
+    fn is_array_of_tables(&self) -> bool {
+        match self {
+            Value::Array(items) => {
+                !items.is_empty() && items.iter().all(|item| matches!(item, Value::Table(_)))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Why a value cannot be written as TOML.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TomlError {
+    /// A TOML document must be a table, e.g. a struct or a map.
+    NotATable { found: &'static str },
+    /// TOML integers are 64-bit signed.
+    OutOfRange(String),
+    /// TOML has no null to put in an array.
+    NullInArray,
+}
+
+impl fmt::Display for TomlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TomlError::NotATable { found } => {
+                write!(f, "a TOML document must be a table, found {found}")
+            }
+            TomlError::OutOfRange(n) => write!(f, "{n} does not fit in a TOML integer"),
+            TomlError::NullInArray => write!(f, "TOML arrays cannot hold null values"),
+        }
+    }
+}
+
+impl error::Error for TomlError {}
+
+/// Writes `s` as a TOML basic string.
+pub fn string(s: &str) -> Value {
+    Value::Scalar(quoted(s))
+}
+
+fn quoted(s: &str) -> String {
+    // A JSON string is a TOML basic string, except that TOML also wants DEL escaped.
+    s.to_json().replace('\u{7f}', "\\u007f")
+}
+
+fn key(key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if bare {
+        key.to_string()
+    } else {
+        quoted(key)
+    }
+}
+
+/// Encodes the fields of a struct as a table, in the given order.
+pub fn table(fields: &[(&str, &dyn ToToml)]) -> Result<Value, TomlError> {
+    let entries = fields
+        .iter()
+        .map(|(name, value)| Ok((name.to_string(), value.to_toml_value()?)))
+        .collect::<Result<_, _>>()?;
+    Ok(Value::Table(entries))
+}
+
+/// Lays `value` out as a TOML document.
+pub fn render(value: &Value) -> Result<String, TomlError> {
+    let Value::Table(entries) = value else {
+        return Err(TomlError::NotATable {
+            found: value.kind(),
+        });
+    };
+    let mut out = String::new();
+    write_table(&mut Vec::new(), entries, &mut out)?;
+    Ok(out)
+}
+
+fn write_table(
+    path: &mut Vec<String>,
+    entries: &[(String, Value)],
+    out: &mut String,
+) -> Result<(), TomlError> {
+    // Plain values must come before any header, or they would land in its table.
+    for (name, value) in entries {
+        match value {
+            Value::Absent | Value::Table(_) => {}
+            value if value.is_array_of_tables() => {}
+            value => out.push_str(&format!("{} = {}\n", key(name), inline(value)?)),
+        }
+    }
+
+    for (name, value) in entries {
+        path.push(key(name));
+        match value {
+            Value::Table(entries) => {
+                write_header(out, &format!("[{}]", path.join(".")));
+                write_table(path, entries, out)?;
+            }
+            Value::Array(items) if value.is_array_of_tables() => {
+                for item in items {
+                    if let Value::Table(entries) = item {
+                        write_header(out, &format!("[[{}]]", path.join(".")));
+                        write_table(path, entries, out)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+        path.pop();
+    }
+    Ok(())
+}
+
+fn write_header(out: &mut String, header: &str) {
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out.push_str(header);
+    out.push('\n');
+}
+
+fn inline(value: &Value) -> Result<String, TomlError> {
+    Ok(match value {
+        Value::Absent => return Err(TomlError::NullInArray),
+        Value::Scalar(s) => s.clone(),
+        Value::Array(items) => {
+            let items = items.iter().map(inline).collect::<Result<Vec<_>, _>>()?;
+            format!("[{}]", items.join(", "))
+        }
+        Value::Table(entries) => {
+            let entries = entries
+                .iter()
+                .filter(|(_, value)| *value != Value::Absent)
+                .map(|(name, value)| Ok(format!("{} = {}", key(name), inline(value)?)))
+                .collect::<Result<Vec<_>, _>>()?;
+            if entries.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{ {} }}", entries.join(", "))
+            }
+        }
+    })
+}
+
+fn array<'a, T, I>(items: I) -> Result<Value, TomlError>
+where
+    T: ToToml + 'a + ?Sized,
+    I: IntoIterator<Item = &'a T>,
+{
+    let items = items
+        .into_iter()
+        .map(ToToml::to_toml_value)
+        .collect::<Result<_, _>>()?;
+    Ok(Value::Array(items))
+}
+
+fn map<'a, K, V, I>(entries: I) -> Result<Value, TomlError>
+where
+    K: AsRef<str> + 'a,
+    V: ToToml + 'a,
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    let entries = entries
+        .into_iter()
+        .map(|(key, value)| Ok((key.as_ref().to_string(), value.to_toml_value()?)))
+        .collect::<Result<_, _>>()?;
+    Ok(Value::Table(entries))
+}
+
+macro_rules! impl_to_toml_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl ToToml for $t {
+                fn to_toml_value(&self) -> Result<Value, TomlError> {
+                    match i64::try_from(*self) {
+                        Ok(n) => Ok(Value::Scalar(n.to_string())),
+                        Err(_) => Err(TomlError::OutOfRange(self.to_string())),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_to_toml_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_to_toml_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl ToToml for $t {
+                fn to_toml_value(&self) -> Result<Value, TomlError> {
+                    let s = if self.is_nan() {
+                        "nan".to_string()
+                    } else if self.is_infinite() {
+                        if *self > 0.0 { "inf" } else { "-inf" }.to_string()
+                    } else {
+                        // Same digits as JSON, which TOML reads as a float.
+                        self.to_json()
+                    };
+                    Ok(Value::Scalar(s))
+                }
+            }
+        )*
+    };
+}
+
+impl_to_toml_for_floats!(f32, f64);
+
+impl ToToml for bool {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        Ok(Value::Scalar(self.to_string()))
+    }
+}
+
+impl ToToml for char {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        Ok(string(self.encode_utf8(&mut [0; 4])))
+    }
+}
+
+impl ToToml for str {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        Ok(string(self))
+    }
+}
+
+impl ToToml for String {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        Ok(string(self))
+    }
+}
+
+impl<T: ToToml + ?Sized> ToToml for &T {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        (**self).to_toml_value()
+    }
+}
+
+impl<T: ToToml + ?Sized> ToToml for Box<T> {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        (**self).to_toml_value()
+    }
+}
+
+impl<T: ToToml> ToToml for Option<T> {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        match self {
+            Some(value) => value.to_toml_value(),
+            None => Ok(Value::Absent),
+        }
+    }
+}
+
+impl<T: ToToml> ToToml for [T] {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        array(self)
+    }
+}
+
+impl<T: ToToml, const N: usize> ToToml for [T; N] {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        array(self)
+    }
+}
+
+impl<T: ToToml> ToToml for Vec<T> {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        array(self)
+    }
+}
+
+impl<K: AsRef<str>, V: ToToml, S> ToToml for HashMap<K, V, S> {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        map(self)
+    }
+}
+
+impl<K: AsRef<str>, V: ToToml> ToToml for BTreeMap<K, V> {
+    fn to_toml_value(&self) -> Result<Value, TomlError> {
+        map(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Coord, Person};
+    use super::*;
+    use serde::de::DeserializeOwned;
+    use std::fmt::Debug;
+
+    /// Asserts that the toml crate reads back `value` from what `ToToml` writes.
+    fn assert_reads_back<T: ToToml + DeserializeOwned + PartialEq + Debug>(value: T) {
+        let toml = value.to_toml().unwrap();
+        assert_eq!(::toml::from_str::<T>(&toml).unwrap(), value, "{toml}");
+    }
+
+    #[test]
+    fn layout_puts_values_before_tables() {
+        let lead = Person::new("John", 42);
+        let members = [Person::new("Jane", 7), Person::new("Joe", 9)];
+        let origin = Some(Coord::new(0, 0));
+        let path = [Coord::new(1, 2)];
+        let vacancies = None::<Person>;
+        let team: BTreeMap<&str, &dyn ToToml> = BTreeMap::from([
+            ("name", &"Rustaceans" as &dyn ToToml),
+            ("lead", &lead),
+            ("members", &members),
+            ("origin", &origin),
+            ("path", &path),
+            ("tags", &["a b", "c"]),
+            ("vacancies", &vacancies),
+        ]);
+
+        assert_eq!(
+            team.to_toml().unwrap(),
+            "name = \"Rustaceans\"\n\
+             tags = [\"a b\", \"c\"]\n\
+             \n\
+             [lead]\n\
+             name = \"John\"\n\
+             age = 42\n\
+             \n\
+             [[members]]\n\
+             name = \"Jane\"\n\
+             age = 7\n\
+             \n\
+             [[members]]\n\
+             name = \"Joe\"\n\
+             age = 9\n\
+             \n\
+             [origin]\n\
+             x = 0\n\
+             y = 0\n\
+             \n\
+             [[path]]\n\
+             x = 1\n\
+             y = 2\n"
+        );
+    }
+
+    #[test]
+    fn values_read_back_with_toml() {
+        assert_reads_back(BTreeMap::from([
+            ("flag".to_string(), vec![true, false]),
+            ("empty".to_string(), vec![]),
+        ]));
+        assert_reads_back(HashMap::from([
+            ("min".to_string(), i64::MIN),
+            ("max".to_string(), i64::MAX),
+        ]));
+        assert_reads_back(BTreeMap::from([
+            ("tiny".to_string(), 5e-324),
+            ("huge".to_string(), 1e300),
+            ("inf".to_string(), f64::NEG_INFINITY),
+        ]));
+        assert_reads_back(BTreeMap::from([
+            (
+                "a.b".to_string(),
+                "line\nbreak \"quoted\" \u{7f}".to_string(),
+            ),
+            (String::new(), "🦀".to_string()),
+        ]));
+        assert_reads_back(BTreeMap::from([(
+            "nested".to_string(),
+            BTreeMap::from([("deeper".to_string(), vec![vec![1], vec![2, 3]])]),
+        )]));
+    }
+
+    #[test]
+    fn values_toml_cannot_hold_are_errors() {
+        assert_eq!(
+            Person::new("John", 42).to_toml().map_err(|e| e.to_string()),
+            Ok("name = \"John\"\nage = 42\n".to_string())
+        );
+        assert_eq!(
+            vec![1].to_toml().unwrap_err().to_string(),
+            "a TOML document must be a table, found an array"
+        );
+        assert_eq!(
+            BTreeMap::from([("n", u64::MAX)]).to_toml().unwrap_err(),
+            TomlError::OutOfRange(u64::MAX.to_string())
+        );
+        assert_eq!(
+            BTreeMap::from([("xs", [Some(1), None])])
+                .to_toml()
+                .unwrap_err(),
+            TomlError::NullInArray
+        );
+    }
+}
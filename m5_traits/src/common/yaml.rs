@@ -0,0 +1,341 @@
+//! A hand-written YAML encoder behind `ToYaml`.
+//!
+//! Values are laid out in block style like serde_yaml does: mappings one key
+//! per line, sequences one `- ` item per line, nested collections indented by
+//! two spaces. Strings are only quoted when they would otherwise read back as
+//! something else, like `"42"`, `"true"` or `"a: b"`.
+
+use super::{ToJson, ToYaml};
+use std::collections::{BTreeMap, HashMap};
+
+/// A YAML value, ready to be laid out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// A scalar as it is written, quotes included.
+    Scalar(String),
+    Sequence(Vec<Node>),
+    /// Entries in the order they are written.
+    Mapping(Vec<(String, Node)>),
+}
+
+/// Writes a string as a plain scalar if that reads back as the same string,
+/// and double-quoted otherwise.
+pub fn string(s: &str) -> Node {
+    Node::Scalar(scalar(s))
+}
+
+fn scalar(s: &str) -> String {
+    if is_plain(s) {
+        return s.to_string();
+    }
+
+    // JSON strings are double-quoted YAML scalars, once the characters YAML
+    // does not allow unescaped are escaped too.
+    let mut out = String::new();
+    for c in s.to_json().chars() {
+        match c {
+            '\u{7f}'..='\u{9f}' | '\u{feff}' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/**
+ * Whether `s` can be written without quotes. Errs on the side of quoting,
+ * e.g. `yes` and `off` are only booleans to YAML 1.1 readers but quoted anyway.
+ */
+fn is_plain(s: &str) -> bool {
+    const RESERVED: [&str; 8] = ["null", "true", "false", "yes", "no", "on", "off", "~"];
+
+    let starts_with_letter = s.starts_with(|c: char| c.is_alphabetic() || c == '_');
+    let safe = s
+        .chars()
+        .all(|c| c.is_alphanumeric() || " _-./()".contains(c));
+    starts_with_letter
+        && safe
+        && !s.ends_with(' ')
+        && !RESERVED.contains(&s.to_lowercase().as_str())
+        && s.parse::<f64>().is_err()
+}
+
+/// Encodes the fields of a struct as a mapping, in the given order.
+pub fn mapping(fields: &[(&str, &dyn ToYaml)]) -> Node {
+    Node::Mapping(
+        fields
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_yaml_node()))
+            .collect(),
+    )
+}
+
+/// Lays `node` out as a YAML document, ending with a newline.
+pub fn render(node: &Node) -> String {
+    let mut out = String::new();
+    for line in lines(node) {
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+fn lines(node: &Node) -> Vec<String> {
+    let mut out = Vec::new();
+    match node {
+        Node::Scalar(s) => out.push(s.clone()),
+        Node::Sequence(items) if items.is_empty() => out.push("[]".to_string()),
+        Node::Mapping(entries) if entries.is_empty() => out.push("{}".to_string()),
+        Node::Sequence(items) => {
+            for item in items {
+                for (i, line) in lines(item).into_iter().enumerate() {
+                    let prefix = if i == 0 { "- " } else { "  " };
+                    out.push(format!("{prefix}{line}"));
+                }
+            }
+        }
+        Node::Mapping(entries) => {
+            for (key, value) in entries {
+                let key = scalar(key);
+                match value {
+                    Node::Sequence(items) if !items.is_empty() => {
+                        // Like serde_yaml, sequences are not indented under their key.
+                        out.push(format!("{key}:"));
+                        out.extend(lines(value));
+                    }
+                    Node::Mapping(entries) if !entries.is_empty() => {
+                        out.push(format!("{key}:"));
+                        out.extend(lines(value).into_iter().map(|line| format!("  {line}")));
+                    }
+                    _ => out.push(format!("{key}: {}", lines(value)[0])),
+                }
+            }
+        }
+    }
+    out
+}
+
+fn sequence<'a, T, I>(items: I) -> Node
+where
+    T: ToYaml + 'a + ?Sized,
+    I: IntoIterator<Item = &'a T>,
+{
+    Node::Sequence(items.into_iter().map(ToYaml::to_yaml_node).collect())
+}
+
+fn map<'a, K, V, I>(entries: I) -> Node
+where
+    K: AsRef<str> + 'a,
+    V: ToYaml + 'a,
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    Node::Mapping(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.as_ref().to_string(), value.to_yaml_node()))
+            .collect(),
+    )
+}
+
+macro_rules! impl_to_yaml_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl ToYaml for $t {
+                fn to_yaml_node(&self) -> Node {
+                    Node::Scalar(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_to_yaml_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_to_yaml_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl ToYaml for $t {
+                fn to_yaml_node(&self) -> Node {
+                    let s = if self.is_nan() {
+                        ".nan".to_string()
+                    } else if self.is_infinite() {
+                        if *self > 0.0 { ".inf" } else { "-.inf" }.to_string()
+                    } else {
+                        // Same digits as JSON, which YAML reads as a float.
+                        self.to_json()
+                    };
+                    Node::Scalar(s)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_yaml_for_floats!(f32, f64);
+
+impl ToYaml for bool {
+    fn to_yaml_node(&self) -> Node {
+        Node::Scalar(self.to_string())
+    }
+}
+
+impl ToYaml for () {
+    fn to_yaml_node(&self) -> Node {
+        Node::Scalar("null".to_string())
+    }
+}
+
+impl ToYaml for char {
+    fn to_yaml_node(&self) -> Node {
+        string(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl ToYaml for str {
+    fn to_yaml_node(&self) -> Node {
+        string(self)
+    }
+}
+
+impl ToYaml for String {
+    fn to_yaml_node(&self) -> Node {
+        string(self)
+    }
+}
+
+impl<T: ToYaml + ?Sized> ToYaml for &T {
+    fn to_yaml_node(&self) -> Node {
+        (**self).to_yaml_node()
+    }
+}
+
+impl<T: ToYaml + ?Sized> ToYaml for Box<T> {
+    fn to_yaml_node(&self) -> Node {
+        (**self).to_yaml_node()
+    }
+}
+
+impl<T: ToYaml> ToYaml for Option<T> {
+    fn to_yaml_node(&self) -> Node {
+        match self {
+            Some(value) => value.to_yaml_node(),
+            None => Node::Scalar("null".to_string()),
+        }
+    }
+}
+
+impl<T: ToYaml> ToYaml for [T] {
+    fn to_yaml_node(&self) -> Node {
+        sequence(self)
+    }
+}
+
+impl<T: ToYaml, const N: usize> ToYaml for [T; N] {
+    fn to_yaml_node(&self) -> Node {
+        sequence(self)
+    }
+}
+
+impl<T: ToYaml> ToYaml for Vec<T> {
+    fn to_yaml_node(&self) -> Node {
+        sequence(self)
+    }
+}
+
+impl<K: AsRef<str>, V: ToYaml, S> ToYaml for HashMap<K, V, S> {
+    fn to_yaml_node(&self) -> Node {
+        map(self)
+    }
+}
+
+impl<K: AsRef<str>, V: ToYaml> ToYaml for BTreeMap<K, V> {
+    fn to_yaml_node(&self) -> Node {
+        map(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Coord, Person};
+    use super::*;
+    use serde::de::DeserializeOwned;
+    use std::fmt::Debug;
+
+    /// Asserts that serde_yaml reads back `value` from what `ToYaml` writes.
+    fn assert_reads_back<T: ToYaml + DeserializeOwned + PartialEq + Debug>(value: T) {
+        let yaml = value.to_yaml();
+        assert_eq!(serde_yaml::from_str::<T>(&yaml).unwrap(), value, "{yaml}");
+    }
+
+    #[test]
+    fn layout_matches_serde_yaml() {
+        let people = vec![Person::new("John", 42), Person::new("Jane", 7)];
+        assert_eq!(
+            people.to_yaml(),
+            "- name: John\n  age: 42\n- name: Jane\n  age: 7\n"
+        );
+
+        let nested: BTreeMap<&str, Vec<Vec<u8>>> =
+            BTreeMap::from([("empty", vec![]), ("grid", vec![vec![1, 2], vec![]])]);
+        assert_eq!(nested.to_yaml(), "empty: []\ngrid:\n- - 1\n  - 2\n- []\n");
+
+        let coords = BTreeMap::from([("origin", Coord::new(0, 0))]);
+        assert_eq!(coords.to_yaml(), "origin:\n  x: 0\n  y: 0\n");
+    }
+
+    #[test]
+    fn strings_are_quoted_only_when_needed() {
+        assert_eq!("John Smith".to_yaml(), "John Smith\n");
+        assert_eq!("42".to_yaml(), "\"42\"\n");
+        assert_eq!("yes".to_yaml(), "\"yes\"\n");
+        assert_eq!("key: value".to_yaml(), "\"key: value\"\n");
+
+        for s in [
+            "",
+            " padded ",
+            "-1",
+            "1e3",
+            ".5",
+            "~",
+            "null",
+            "True",
+            "NaN",
+            "inf",
+            "- item",
+            "a #comment",
+            "[1]",
+            "{}",
+            "*alias",
+            "&anchor",
+            "!tag",
+            "|",
+            ">",
+            "'",
+            "\"",
+            "line\nbreak",
+            "tab\there",
+            "\u{7f}",
+            "🦀",
+        ] {
+            assert_reads_back(s.to_string());
+        }
+    }
+
+    #[test]
+    fn values_read_back_with_serde_yaml() {
+        assert_reads_back(true);
+        assert_reads_back(i64::MIN);
+        assert_reads_back(u64::MAX);
+        assert_reads_back(0.1f64);
+        assert_reads_back(1e300f64);
+        assert_reads_back(-2.5e-8f64);
+        assert_reads_back(f64::INFINITY);
+        assert_reads_back('x');
+        assert_reads_back(vec![Some(1), None]);
+        assert_reads_back(vec![vec![vec![1]], vec![]]);
+        assert_reads_back(HashMap::from([
+            ("a".to_string(), vec![1.5]),
+            ("b c".to_string(), vec![]),
+        ]));
+        assert!(f64::NAN.to_yaml_node() == Node::Scalar(".nan".to_string()));
+    }
+}
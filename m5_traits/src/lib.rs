@@ -1,6 +1,12 @@
 #![allow(unused)]
 
+// Lets `#[derive(ToJson)]` refer to `::m5_traits` from inside this crate.
+extern crate self as m5_traits;
+
 mod common;
 mod t01_traits;
 mod t02_impl_as_arguments;
 mod t03_impl_as_return_values;
+
+// What `#[derive(ToJson)]` expands to, so it also works in other crates.
+pub use common::{json, ToJson};
@@ -1,8 +1,7 @@
 use super::common::{Coord, Person, ToJson};
-use serde::{Deserialize, Serialize};
 use lib::delim;
+use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "skip")]
 #[test]
 fn person_to_json() {
     delim!();
@@ -15,7 +14,6 @@ fn person_to_json() {
     delim!();
 }
 
-#[cfg(feature = "skip")]
 #[test]
 fn coord_to_json() {
     delim!();
@@ -32,7 +30,7 @@ fn coord_to_json() {
 fn blank_implementation_for_references_for_car() {
     delim!();
 
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, ToJson)]
     struct Car {
         name: String,
         make: String,
@@ -46,17 +44,10 @@ fn blank_implementation_for_references_for_car() {
     };
 
     /*
-     * If we implement `ToJson` trait for `Car` struct,
-     * we can call `to_json` method on `car` instance, `&Car`, and `&mut Car`.
-     * No need to define blanket implementations for `&T` and `&mut T`.
+     * `#[derive(ToJson)]` implements `ToJson` trait for `Car` struct,
+     * so we can call `to_json` method on `car` instance, `&Car`, and `&mut Car`.
+     * No need to define implementations for `&Car` and `&mut Car`.
      */
-    impl ToJson for Car {
-        fn to_json(&self) -> String {
-            println!("toJson for Car");
-            serde_json::to_string(self).unwrap()
-        }
-    }
-
     println!("{}", car.to_json());
     delim!();
 
@@ -83,7 +74,6 @@ fn blank_implementation_for_references_for_car() {
     delim!();
 }
 
-#[cfg(feature = "skip")]
 #[test]
 fn trait_bounds() {
     // Use trait bound
@@ -17,10 +17,10 @@ mod tests {
         }
 
         let person = Person::new("John", 42);
-        // print_json(&person);
+        print_json(&person);
 
         let point = Coord::new(1, 2);
-        // print_json(&point);
+        print_json(&point);
 
         delim!();
     }
@@ -44,8 +44,8 @@ mod tests {
         let person = Person::new("John", 42);
         let point = Coord::new(1, 2);
 
-        // print_json1(&person, &point);
-        // print_json2(&person, &point);
+        print_json1(&person, &point);
+        print_json2(&person, &point);
         // print_json3(&person, &point);
 
         delim!();
@@ -62,14 +62,14 @@ mod tests {
         let person = Person::new("John", 42);
         let point = Coord::new(1, 2);
 
-        // print_json(&person);
-        // print_json(&point);
+        print_json(&person);
+        print_json(&point);
         delim!();
 
-        // let jsons: Vec<&dyn ToJson> = vec![&person, &point];
-        // for json in jsons {
-        //     print_json(json);
-        // }
+        let jsons: Vec<&dyn ToJson> = vec![&person, &point];
+        for json in jsons {
+            print_json(json);
+        }
 
         delim!();
     }
@@ -85,13 +85,13 @@ mod tests {
         let person = Person::new("John", 42);
         let point = Coord::new(1, 2);
 
-        // print_json(Box::new(person));
-        // print_json(Box::new(point));
+        print_json(Box::new(person));
+        print_json(Box::new(point));
         delim!();
 
         let jsons: Vec<Box<dyn ToJson>> = vec![
-            // Box::new(Person::new("John", 42)),
-            // Box::new(Coord::new(1, 2)),
+            Box::new(Person::new("John", 42)),
+            Box::new(Coord::new(1, 2)),
         ];
 
         for json in jsons {
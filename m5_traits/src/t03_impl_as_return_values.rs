@@ -5,7 +5,7 @@ use lib::delim;
 mod tests {
     use super::*;
 
-    #[cfg(feature = "skip")]
+    #[cfg(feature = "broken-examples")]
     #[test]
     fn impl_as_a_return_values1() {
         delim!();
@@ -20,7 +20,7 @@ mod tests {
         delim!();
     }
 
-    #[cfg(feature = "skip")]
+    #[cfg(feature = "broken-examples")]
     #[test]
     fn impl_as_a_return_values2() {
         enum Kind {
@@ -45,13 +45,12 @@ mod tests {
         let vs = vec![Coord { x: 1, y: 2 }, Coord { x: 3, y: 4 }];
 
         for v in vs {
-            // println!("{}", v.to_json());
+            println!("{}", v.to_json());
         }
 
         delim!();
     }
 
-    #[cfg(feature = "skip")]
     #[test]
     fn using_trait_objects_references_by_casting_to_dyn() {
         delim!();
@@ -69,7 +68,6 @@ mod tests {
         delim!();
     }
 
-    #[cfg(feature = "skip")]
     #[test]
     fn using_explicitly_type_annotated_trait_objects_references() {
         delim!();
@@ -87,7 +85,6 @@ mod tests {
         delim!();
     }
 
-    #[cfg(feature = "skip")]
     #[test]
     fn using_explicitly_type_annotated_boxed_trait_objects() {
         delim!();
@@ -105,7 +102,6 @@ mod tests {
         delim!();
     }
 
-    #[cfg(feature = "skip")]
     #[test]
     fn impl_as_a_return_value_solution_using_boxed_dyn() {
         delim!();
@@ -130,7 +126,72 @@ mod tests {
         delim!();
     }
 
-    #[cfg(feature = "skip")]
+    #[test]
+    fn boxed_dyn_values_as_sections_of_a_document() {
+        use crate::common::document::Document;
+
+        delim!();
+
+        enum Kind {
+            Person,
+            Coord,
+        }
+
+        fn get_json(kind: Kind) -> Box<dyn ToJson> {
+            match kind {
+                Kind::Person => Box::new(Person::new("John", 42)),
+                Kind::Coord => Box::new(Coord::new(1, 2)),
+            }
+        }
+
+        let mut document = Document::new();
+        document.insert_boxed("author", get_json(Kind::Person));
+        document.insert_boxed("location", get_json(Kind::Coord));
+        document.insert("tags", vec!["traits", "dyn"]);
+        println!("{}", document.to_json_pretty(2));
+
+        delim!();
+    }
+
+    #[cfg(feature = "broken-examples")]
+    #[test]
+    fn impl_as_a_return_value_of_serializers() {
+        use crate::common::format::{CsvSerializer, Format, JsonSerializer, Serializer};
+
+        // Both arms implement `Serializer<Person>`, but `impl` still means one type.
+        fn get_serializer(format: Format) -> impl Serializer<Person> {
+            match format {
+                Format::Csv => CsvSerializer,
+                _ => JsonSerializer,
+            }
+        }
+    }
+
+    #[test]
+    fn format_dispatch_using_boxed_dyn_serializers() {
+        use crate::common::format::{json_serializer, serializer, Format, Serializer};
+
+        delim!();
+
+        let people = vec![Person::new("John", 42), Person::new("Jane", 7)];
+
+        // One concrete type behind the `impl`, so no box is needed.
+        let json = json_serializer();
+        println!("{}", json.serialize(&people).unwrap());
+
+        // A different type per format, so they are boxed behind `dyn`.
+        for format in [Format::Yaml, Format::Csv] {
+            let serializer: Box<dyn Serializer<Vec<Person>>> = serializer(format);
+            println!("{format}:\n{}", serializer.serialize(&people).unwrap());
+        }
+
+        let serializer = serializer::<Person>(Format::Toml);
+        println!("{}", serializer.serialize(&people[0]).unwrap());
+
+        delim!();
+    }
+
+    #[cfg(feature = "broken-examples")]
     fn impl_as_a_return_value_wrong_solution() {
         enum Kind {
             Person,
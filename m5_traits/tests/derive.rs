@@ -0,0 +1,43 @@
+use m5_traits::ToJson;
+
+#[derive(m5_traits::ToJson)]
+struct Point<T> {
+    x: T,
+    #[json(rename = "y-axis")]
+    y: T,
+    #[json(skip)]
+    label: &'static str,
+}
+
+#[derive(ToJson)]
+enum Shape {
+    Empty,
+    Circle { r: u32 },
+    Line(Point<i32>, Point<i32>),
+}
+
+#[test]
+fn should_derive_to_json_outside_the_crate() {
+    let point = Point {
+        x: 1,
+        y: -2,
+        label: "origin",
+    };
+    assert_eq!(point.to_json(), r#"{"x":1,"y-axis":-2}"#);
+    assert_eq!(point.label, "origin");
+
+    assert_eq!(Shape::Empty.to_json(), r#""Empty""#);
+    assert_eq!(Shape::Circle { r: 3 }.to_json(), r#"{"Circle":{"r":3}}"#);
+    let line = Shape::Line(
+        point,
+        Point {
+            x: 0,
+            y: 0,
+            label: "",
+        },
+    );
+    assert_eq!(
+        line.to_json(),
+        r#"{"Line":[{"x":1,"y-axis":-2},{"x":0,"y-axis":0}]}"#
+    );
+}
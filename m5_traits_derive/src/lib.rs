@@ -0,0 +1,190 @@
+//! `#[derive(ToJson)]` for `m5_traits::ToJson`.
+//!
+//! The generated JSON follows serde_json's defaults: structs with named fields
+//! become objects, newtype structs their inner value, tuple structs arrays and
+//! unit structs `null`. Enums are externally tagged, e.g. `{"Circle":{"r":1}}`
+//! or `"Empty"` for a unit variant.
+//!
+//! Fields and variants accept `#[json(rename = "...")]` and fields also
+//! `#[json(skip)]`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Fields, GenericParam, Ident,
+    LitStr,
+};
+
+#[proc_macro_derive(ToJson, attributes(json))]
+pub fn derive_to_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// What `#[json(...)]` says about a field or variant.
+#[derive(Default)]
+struct JsonAttrs {
+    rename: Option<String>,
+    skip: bool,
+}
+
+impl JsonAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut json = JsonAttrs::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("json")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    json.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    json.skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `rename = \"...\"` or `skip`"))
+                }
+            })?;
+        }
+        Ok(json)
+    }
+
+    fn name(&self, ident: &Ident) -> String {
+        self.rename.clone().unwrap_or_else(|| ident.to_string())
+    }
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    for param in &mut input.generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(parse_quote!(::m5_traits::ToJson));
+        }
+    }
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, json) = fields_to_json(&data.fields, quote!(Self))?;
+            quote! {
+                let #pattern = self;
+                #json
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                let attrs = JsonAttrs::parse(&variant.attrs)?;
+                if attrs.skip {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "`#[json(skip)]` is not supported on variants",
+                    ));
+                }
+                let tag = attrs.name(&variant.ident);
+                let ident = &variant.ident;
+                let (pattern, json) = fields_to_json(&variant.fields, quote!(Self::#ident))?;
+
+                let arm = match variant.fields {
+                    Fields::Unit => quote! {
+                        #pattern => ::m5_traits::ToJson::to_json(#tag),
+                    },
+                    _ => quote! {
+                        #pattern => {
+                            let value = { #json };
+                            ::m5_traits::json::object(&[(#tag, &::m5_traits::json::Raw(value))])
+                        }
+                    },
+                };
+                arms.push(arm);
+            }
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "`ToJson` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::m5_traits::ToJson for #name #ty_generics #where_clause {
+            fn to_json(&self) -> ::std::string::String {
+                #body
+            }
+        }
+    })
+}
+
+/**
+ * Returns a pattern binding the fields of a struct or variant called `path`,
+ * and an expression encoding those bindings as JSON.
+ */
+fn fields_to_json(
+    fields: &Fields,
+    path: TokenStream2,
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    match fields {
+        Fields::Named(named) => {
+            let mut bindings = Vec::new();
+            let mut entries = Vec::new();
+            for field in &named.named {
+                let attrs = JsonAttrs::parse(&field.attrs)?;
+                if attrs.skip {
+                    continue;
+                }
+                let ident = field.ident.as_ref().expect("named fields have names");
+                let name = attrs.name(ident);
+                bindings.push(ident);
+                entries.push(quote!((#name, #ident as &dyn ::m5_traits::ToJson)));
+            }
+            Ok((
+                quote!(#path { #(#bindings,)* .. }),
+                quote!(::m5_traits::json::object(&[#(#entries),*])),
+            ))
+        }
+        Fields::Unnamed(unnamed) => {
+            let mut bindings = Vec::new();
+            let mut kept = Vec::new();
+            for (i, field) in unnamed.unnamed.iter().enumerate() {
+                let attrs = JsonAttrs::parse(&field.attrs)?;
+                if attrs.rename.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "`#[json(rename)]` needs a named field",
+                    ));
+                }
+                if attrs.skip {
+                    bindings.push(quote!(_));
+                } else {
+                    let binding = format_ident!("field{i}");
+                    bindings.push(quote!(#binding));
+                    kept.push(binding);
+                }
+            }
+
+            let pattern = quote!(#path(#(#bindings),*));
+            let json = match &kept[..] {
+                // A newtype is written as the value it wraps
+                [single] if unnamed.unnamed.len() == 1 => {
+                    quote!(::m5_traits::ToJson::to_json(#single))
+                }
+                [] => quote!(::std::string::String::from("[]")),
+                _ => quote! {
+                    ::m5_traits::ToJson::to_json(
+                        &[#(#kept as &dyn ::m5_traits::ToJson),*]
+                    )
+                },
+            };
+            Ok((pattern, json))
+        }
+        Fields::Unit => Ok((path, quote!(::std::string::String::from("null")))),
+    }
+}
@@ -168,7 +168,7 @@ fn closures_may_implement_multiple_traits_case_for_fn_once_closure() {
     };
 
     delim!();
-    // accept_fn(closure); // oops!
+    // accept_fn(closure); // oops! (testing/compile_fail/m6_closures/fn_once_closure_as_fn.rs)
     // accept_fn_mut(closure); // oops!
     accept_fn_once(closure);
     delim!();
@@ -225,6 +225,7 @@ fn capturing_closures_cannot_be_coerced_to_fn_pointers() {
     let k = 10;
     let add = |x: i32, y: i32| x + y + k;
 
+    // testing/compile_fail/m6_closures/capturing_closure_as_fn_pointer.rs
     // let bo: Binop = add;
     // println!("{}", bo(3, 4));
 }
@@ -245,6 +246,7 @@ fn no_two_closures_have_the_same_type() {
         g(f(x))
     }
 
+    // testing/compile_fail/m6_closures/compose_two_closures.rs
     // let result = compose(
     //     5,
     //     (|n: i32| n + n), // Change n + n to n * n and see what happens ...
@@ -263,27 +265,12 @@ fn no_two_closures_have_the_same_type() {
     delim!();
 }
 
-#[cfg(feature = "skip")]
-#[test]
-fn no_two_function_items_have_the_same_type() {
-    fn compose<F>(x: i32, f: F, g: F) -> i32
-    where
-        F: Fn(i32) -> i32,
-    {
-        g(f(x))
-    }
-
-    fn double(x: i32) -> i32 {
-        x + x
-    }
-
-    fn square(x: i32) -> i32 {
-        x * x
-    }
-
-    let result = compose(5, double, square); // what if casting both to fn(i32) -> i32?
-                                             // println!("{result}")
-}
+// Compile-fail test: testing/compile_fail/m6_closures/compose_two_function_items.rs
+//
+// fn double(x: i32) -> i32 { x + x }
+// fn square(x: i32) -> i32 { x * x }
+//
+// let result = compose(5, double, square); // what if casting both to fn(i32) -> i32?
 
 #[test]
 fn solution_to_no_two_closures_have_the_same() {
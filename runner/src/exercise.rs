@@ -0,0 +1,212 @@
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// An exercise living in the `exercises` module of one of the lab crates.
+#[derive(Debug)]
+pub struct Exercise {
+    pub name: &'static str,
+    pub package: &'static str,
+}
+
+/**
+ * The registry of all exercises, in the order they should be solved.
+ */
+pub const EXERCISES: &[Exercise] = &[
+    Exercise::new("strings1", "m1_basics"),
+    Exercise::new("strings2", "m1_basics"),
+    Exercise::new("strings3", "m1_basics"),
+    Exercise::new("strings4", "m1_basics"),
+    Exercise::new("vecs1", "m1_basics"),
+    Exercise::new("vecs2", "m1_basics"),
+    Exercise::new("move_semantics1", "m2_ownership"),
+    Exercise::new("move_semantics2", "m2_ownership"),
+    Exercise::new("move_semantics3", "m2_ownership"),
+    Exercise::new("move_semantics4", "m2_ownership"),
+    Exercise::new("move_semantics5", "m2_ownership"),
+    Exercise::new("sum_of_vecs1", "m4_error_handling"),
+    Exercise::new("sum_of_vecs2", "m4_error_handling"),
+    Exercise::new("sum_of_vecs3", "m4_error_handling"),
+    Exercise::new("sum_of_vecs4", "m4_error_handling"),
+    Exercise::new("sum_of_vecs5", "m4_error_handling"),
+    Exercise::new("sum_of_vecs6", "m4_error_handling"),
+    Exercise::new("sum_of_vecs7", "m4_error_handling"),
+    Exercise::new("sum_of_vecs8", "m4_error_handling"),
+];
+
+pub fn find(name: &str) -> Option<&'static Exercise> {
+    EXERCISES.iter().find(|e| e.name == name)
+}
+
+/// The root of the workspace, i.e. the parent of the `runner` crate.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate must live inside the workspace")
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Passed { tests: usize },
+    Failed { output: String },
+    CompileError { output: String },
+}
+
+impl Outcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Outcome::Passed { .. })
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Passed { tests } => write!(f, "PASS ({tests} tests)"),
+            Outcome::Failed { .. } => write!(f, "FAIL"),
+            Outcome::CompileError { .. } => write!(f, "COMPILE ERROR"),
+        }
+    }
+}
+
+impl Exercise {
+    const fn new(name: &'static str, package: &'static str) -> Self {
+        Self { name, package }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        workspace_root()
+            .join(self.package)
+            .join("src")
+            .join("exercises")
+            .join(format!("{}.rs", self.name))
+    }
+
+    /// The reference solution, kept in `solutions/<package>/<name>.rs`.
+    pub fn solution_path(&self) -> PathBuf {
+        workspace_root()
+            .join("solutions")
+            .join(self.package)
+            .join(format!("{}.rs", self.name))
+    }
+
+    /// Test names of an exercise all start with this prefix.
+    pub fn test_filter(&self) -> String {
+        format!("exercises::{}::", self.name)
+    }
+
+    /// Exercises that do not compile until solved are hidden behind this
+    /// feature of their crate.
+    pub fn feature(&self) -> String {
+        format!("exercise-{}", self.name)
+    }
+
+    /// Whether the exercise's crate declares [`Exercise::feature`].
+    pub fn is_feature_gated(&self) -> std::io::Result<bool> {
+        let manifest = fs::read_to_string(workspace_root().join(self.package).join("Cargo.toml"))?;
+        let feature = self.feature();
+
+        Ok(manifest
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .any(|(key, _)| key.trim() == feature))
+    }
+
+    /// Compiles the exercise's crate and runs the tests of this exercise only.
+    pub fn run(&self) -> std::io::Result<Outcome> {
+        self.run_in(workspace_root())
+    }
+
+    /// Like [`Exercise::run`], but in a copy of the workspace located at `root`.
+    pub fn run_in(&self, root: &Path) -> std::io::Result<Outcome> {
+        let mut command = Command::new(env!("CARGO"));
+        command.current_dir(root);
+        command.args(["test", "--quiet", "-p", self.package, "--lib"]);
+        if root != workspace_root() && env::var_os("CARGO_TARGET_DIR").is_none() {
+            // Share already compiled dependencies with the real workspace
+            command.env("CARGO_TARGET_DIR", workspace_root().join("target"));
+        }
+        if self.is_feature_gated()? {
+            command.args(["--features", &self.feature()]);
+        }
+        let output = command.arg("--").arg(self.test_filter()).output()?;
+
+        let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+        text.push_str(&String::from_utf8_lossy(&output.stderr));
+
+        let outcome = match passed_tests(&text) {
+            None => Outcome::CompileError { output: text },
+            Some(_) if !output.status.success() => Outcome::Failed { output: text },
+            Some(tests) => Outcome::Passed { tests },
+        };
+
+        Ok(outcome)
+    }
+}
+
+/// Extracts the number of passed tests from `cargo test` output, or `None`
+/// if the test binary never ran (i.e. the crate failed to compile).
+fn passed_tests(output: &str) -> Option<usize> {
+    output
+        .lines()
+        .filter_map(|line| line.strip_prefix("test result: "))
+        .map(|line| {
+            line.split_once(". ")
+                .and_then(|(_, counts)| counts.split_once(" passed"))
+                .and_then(|(n, _)| n.parse::<usize>().ok())
+                .unwrap_or(0)
+        })
+        .reduce(|acc, n| acc + n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_registered_exercise_has_a_source_file() {
+        for exercise in EXERCISES {
+            assert!(exercise.path().is_file(), "{:?}", exercise.path());
+        }
+    }
+
+    #[test]
+    fn every_registered_exercise_has_a_reference_solution() {
+        for exercise in EXERCISES {
+            let path = exercise.solution_path();
+            assert!(path.is_file(), "{path:?}");
+        }
+    }
+
+    #[test]
+    fn exercise_names_are_unique() {
+        let names: HashSet<_> = EXERCISES.iter().map(|e| e.name).collect();
+        assert_eq!(names.len(), EXERCISES.len());
+    }
+
+    #[test]
+    fn should_detect_feature_gated_exercises() {
+        let strings1 = find("strings1").unwrap();
+        assert!(strings1.is_feature_gated().unwrap());
+
+        let strings3 = find("strings3").unwrap();
+        assert!(!strings3.is_feature_gated().unwrap());
+    }
+
+    #[test]
+    fn should_count_passed_tests() {
+        let output = "running 2 tests\n..\ntest result: ok. 2 passed; 0 failed; 0 ignored;\n";
+        assert_eq!(passed_tests(output), Some(2));
+
+        let output = "test result: FAILED. 1 passed; 1 failed; 0 ignored;\n";
+        assert_eq!(passed_tests(output), Some(1));
+    }
+
+    #[test]
+    fn should_return_none_if_tests_never_ran() {
+        let output = "error[E0308]: mismatched types\nerror: could not compile `m1_basics`";
+        assert_eq!(passed_tests(output), None);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    exercise::{self, Exercise},
+    progress::unknown_exercise,
+    state::Progress,
+};
+use serde::Deserialize;
+use std::io;
+
+/// The hint manifest, kept next to the exercise registry.
+const MANIFEST: &str = include_str!("../hints.toml");
+
+#[derive(Deserialize)]
+struct Manifest {
+    exercises: Vec<ExerciseHints>,
+}
+
+#[derive(Deserialize)]
+struct ExerciseHints {
+    name: String,
+    hints: Vec<String>,
+}
+
+/// All hints of an exercise, from a gentle nudge to (almost) the answer.
+pub fn hints(exercise: &Exercise) -> io::Result<Vec<String>> {
+    let manifest: Manifest = toml::from_str(MANIFEST).map_err(io::Error::other)?;
+
+    manifest
+        .exercises
+        .into_iter()
+        .find(|e| e.name == exercise.name)
+        .map(|e| e.hints)
+        .ok_or_else(|| io::Error::other(format!("no hints for `{}`", exercise.name)))
+}
+
+/**
+ * Reveals the hint at `level` (1-based), or the next hint not seen yet if no
+ * level is given, and records how many hints of the exercise were used.
+ */
+pub fn hint(progress: &mut Progress, name: &str, level: Option<&str>) -> io::Result<bool> {
+    let exercise = exercise::find(name).ok_or_else(|| unknown_exercise(name))?;
+    let hints = hints(exercise)?;
+
+    let used = progress.hints_used(exercise);
+    let level = match level {
+        Some(level) => level
+            .parse::<u32>()
+            .ok()
+            .filter(|&n| 1 <= n && n as usize <= hints.len())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("hint level must be between 1 and {}", hints.len()),
+                )
+            })?,
+        None => (used + 1).min(hints.len() as u32),
+    };
+
+    progress.use_hints(exercise, level);
+
+    println!("Hint {level}/{} for {name}:", hints.len());
+    println!("  {}", hints[level as usize - 1]);
+    if level as usize == hints.len() {
+        println!("That was the last hint.");
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::EXERCISES;
+
+    #[test]
+    fn every_registered_exercise_has_hints() {
+        for exercise in EXERCISES {
+            let hints = hints(exercise).unwrap();
+            assert!(!hints.is_empty(), "{}", exercise.name);
+        }
+    }
+
+    #[test]
+    fn every_manifest_entry_is_a_registered_exercise() {
+        let manifest: Manifest = toml::from_str(MANIFEST).unwrap();
+
+        for entry in manifest.exercises {
+            assert!(exercise::find(&entry.name).is_some(), "{}", entry.name);
+        }
+    }
+
+    #[test]
+    fn should_reveal_hints_one_at_a_time() {
+        let mut progress = Progress::default();
+        let exercise = exercise::find("strings3").unwrap();
+
+        hint(&mut progress, "strings3", None).unwrap();
+        assert_eq!(progress.hints_used(exercise), 1);
+
+        hint(&mut progress, "strings3", None).unwrap();
+        assert_eq!(progress.hints_used(exercise), 2);
+
+        // Looking back at an earlier hint does not forget the later ones
+        hint(&mut progress, "strings3", Some("1")).unwrap();
+        assert_eq!(progress.hints_used(exercise), 2);
+
+        hint(&mut progress, "strings3", None).unwrap();
+        hint(&mut progress, "strings3", None).unwrap();
+        assert_eq!(progress.hints_used(exercise), 3);
+    }
+
+    #[test]
+    fn should_reject_out_of_range_levels() {
+        let mut progress = Progress::default();
+
+        assert!(hint(&mut progress, "strings3", Some("0")).is_err());
+        assert!(hint(&mut progress, "strings3", Some("4")).is_err());
+        assert!(hint(&mut progress, "strings3", Some("two")).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+mod exercise;
+mod hint;
+mod progress;
+mod solutions;
+mod state;
+mod verify;
+mod watch;
+
+use state::State;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: cargo run -p runner -- [--user <name>] <command>
+
+Commands:
+  verify              Compile and test every exercise in order, stopping at the first failure
+  next                Run the first exercise that is not completed yet
+  watch [exercise]    Re-run an exercise (default: the next unfinished one) whenever it is saved
+  hint <exercise> [level]
+                      Reveal the next hint of an exercise, or the hint at `level`
+  diff <exercise>     Show your version of an exercise next to the reference solution
+  check-solutions     Run every exercise's tests against its reference solution
+  progress            Show completed and attempted exercises
+  reset <exercise>    Forget the recorded progress of an exercise
+
+Progress is stored per user (default: $USER) in .runner-state.json.";
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    let user = match args.iter().position(|arg| arg == "--user") {
+        Some(i) if i + 1 < args.len() => {
+            args.remove(i);
+            args.remove(i)
+        }
+        Some(_) => {
+            println!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+        None => std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .unwrap_or_else(|_| "default".to_string()),
+    };
+
+    let path = State::default_path();
+    let mut state = match State::load(&path) {
+        Ok(state) => state,
+        Err(e) => {
+            eprintln!("Error: cannot load {}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args[..] {
+        ["verify"] => verify::verify(state.user(&user)),
+        ["next"] => progress::next(state.user(&user)),
+        ["watch"] => watch::watch(&mut state, &path, &user, None),
+        ["watch", name] => watch::watch(&mut state, &path, &user, Some(name)),
+        ["progress"] => {
+            progress::show(&user, state.user(&user));
+            Ok(true)
+        }
+        ["hint", name] => hint::hint(state.user(&user), name, None),
+        ["hint", name, level] => hint::hint(state.user(&user), name, Some(level)),
+        ["diff", name] => solutions::diff(name),
+        ["check-solutions"] => solutions::check(),
+        ["reset", name] => progress::reset(state.user(&user), name),
+        _ => {
+            println!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Saved even when the command failed, to keep the progress it made first.
+    let saved = state.save(&path);
+    if let Err(e) = &result {
+        eprintln!("Error: {e}");
+    }
+    if let Err(e) = &saved {
+        eprintln!("Error: cannot save {}: {e}", path.display());
+    }
+
+    match (result, saved) {
+        (Ok(true), Ok(())) => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    exercise::{self, Outcome, EXERCISES},
+    state::{self, Progress},
+};
+use lib::delim;
+use std::io;
+
+/// Prints what the learner has completed and attempted so far.
+pub fn show(user: &str, progress: &Progress) {
+    let mut package = "";
+
+    for exercise in EXERCISES {
+        if exercise.package != package {
+            package = exercise.package;
+            delim!();
+            println!("{package}");
+            delim!();
+        }
+
+        let Some(p) = progress.get(exercise) else {
+            println!("  {:<20}-", exercise.name);
+            continue;
+        };
+
+        let status = match (p.completed, p.attempts) {
+            (true, _) => "done",
+            (false, 0) => "-",
+            (false, _) => "attempted",
+        };
+        let when = p.last_attempt_at.map(state::format_timestamp);
+        println!(
+            "  {:<20}{status:<11}{:>3} attempts {:>2} hints  {}",
+            exercise.name,
+            p.attempts,
+            p.hints_used,
+            when.unwrap_or_default()
+        );
+        if let Some(failure) = &p.last_failure {
+            println!("  {:<20}last failure: {failure}", "");
+        }
+    }
+    delim!();
+
+    let completed = EXERCISES
+        .iter()
+        .filter(|e| progress.is_completed(e))
+        .count();
+    println!(
+        "{user}: {completed}/{} exercises completed ({}%)",
+        EXERCISES.len(),
+        completed * 100 / EXERCISES.len()
+    );
+}
+
+/// Forgets the recorded progress of a single exercise.
+pub fn reset(progress: &mut Progress, name: &str) -> io::Result<bool> {
+    let exercise = exercise::find(name).ok_or_else(|| unknown_exercise(name))?;
+
+    if progress.reset(exercise) {
+        println!("Progress of `{name}` has been reset.");
+    } else {
+        println!("There is no progress recorded for `{name}`.");
+    }
+    Ok(true)
+}
+
+/// Resumes at the first unfinished exercise and runs it.
+pub fn next(progress: &mut Progress) -> io::Result<bool> {
+    let Some(exercise) = progress.next_unfinished() else {
+        println!("All exercises are done!");
+        return Ok(true);
+    };
+
+    println!("Next up: {} ({})", exercise.name, exercise.path().display());
+    let outcome = exercise.run()?;
+    progress.record(exercise, &outcome, state::now());
+
+    match &outcome {
+        Outcome::Passed { .. } => {
+            println!("{}: {outcome}", exercise.name);
+            Ok(true)
+        }
+        Outcome::Failed { output } | Outcome::CompileError { output } => {
+            println!("{output}");
+            delim!();
+            println!("{}: {outcome}", exercise.name);
+            Ok(false)
+        }
+    }
+}
+
+pub fn unknown_exercise(name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("unknown exercise `{name}`"),
+    )
+}
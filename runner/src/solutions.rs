@@ -0,0 +1,179 @@
+use crate::{
+    exercise::{self, workspace_root, Outcome, EXERCISES},
+    progress::unknown_exercise,
+};
+use lib::delim;
+use std::{fs, io, path::Path};
+
+/// Entries of the workspace root that are not copied into the scratch workspace.
+const SKIPPED: &[&str] = &["target", ".git", "solutions", ".runner-state.json"];
+
+/**
+ * Runs the tests of every exercise against its reference solution instead of
+ * the learner's code, which proves that the tests can actually be passed.
+ *
+ * The solutions are copied over the exercises of a scratch copy of the
+ * workspace in `target/solutions-check`, so the learner's files are never
+ * touched.
+ */
+pub fn check() -> io::Result<bool> {
+    let scratch = workspace_root().join("target").join("solutions-check");
+    if scratch.exists() {
+        fs::remove_dir_all(&scratch)?;
+    }
+    copy_dir(workspace_root(), &scratch, SKIPPED)?;
+
+    let mut all_passed = true;
+    let mut package = "";
+
+    for exercise in EXERCISES {
+        let target = scratch
+            .join(exercise.package)
+            .join("src")
+            .join("exercises")
+            .join(format!("{}.rs", exercise.name));
+        fs::copy(exercise.solution_path(), target)?;
+
+        let outcome = exercise.run_in(&scratch)?;
+
+        if exercise.package != package {
+            package = exercise.package;
+            delim!();
+            println!("{package}");
+            delim!();
+        }
+        println!("  {:<20}{outcome}", exercise.name);
+
+        if let Outcome::Failed { output } | Outcome::CompileError { output } = &outcome {
+            println!("{output}");
+            all_passed = false;
+        }
+    }
+    delim!();
+
+    if all_passed {
+        println!("All reference solutions pass their exercise's tests.");
+    }
+    Ok(all_passed)
+}
+
+/// Recursively copies `from` into `to`, leaving out the `skipped` entries and
+/// any nested `target` directories.
+fn copy_dir(from: &Path, to: &Path, skipped: &[&str]) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if skipped.iter().any(|skipped| name == *skipped) {
+            continue;
+        }
+
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &to.join(&name), &["target"])?;
+        } else {
+            fs::copy(entry.path(), to.join(&name))?;
+        }
+    }
+    Ok(())
+}
+
+/// Shows the learner's version of an exercise next to the reference solution.
+pub fn diff(name: &str) -> io::Result<bool> {
+    let exercise = exercise::find(name).ok_or_else(|| unknown_exercise(name))?;
+
+    let yours = fs::read_to_string(exercise.path())?;
+    let reference = fs::read_to_string(exercise.solution_path())?;
+
+    println!("--- {} (yours)", exercise.path().display());
+    println!("+++ {} (reference)", exercise.solution_path().display());
+
+    for line in diff_lines(&yours, &reference) {
+        match line {
+            Line::Same(text) => println!("  {text}"),
+            Line::Removed(text) => println!("- {text}"),
+            Line::Added(text) => println!("+ {text}"),
+        }
+    }
+
+    Ok(true)
+}
+
+#[derive(Debug, PartialEq)]
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// A line-based diff built on the longest common subsequence of both texts.
+fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<Line<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j]: length of the LCS of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|line| Line::Removed(line)));
+    lines.extend(new[j..].iter().map(|line| Line::Added(line)));
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_diff_changed_lines() {
+        let yours = "fn to_int(s: &str) -> i32 {\n    todo!()\n}\n";
+        let reference = "fn to_int(s: &str) -> i32 {\n    s.parse().unwrap()\n}\n";
+
+        assert_eq!(
+            diff_lines(yours, reference),
+            [
+                Line::Same("fn to_int(s: &str) -> i32 {"),
+                Line::Removed("    todo!()"),
+                Line::Added("    s.parse().unwrap()"),
+                Line::Same("}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_diff_added_and_removed_lines() {
+        assert_eq!(
+            diff_lines("a\nb\nc", "a\nc\nd"),
+            [
+                Line::Same("a"),
+                Line::Removed("b"),
+                Line::Same("c"),
+                Line::Added("d"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use crate::exercise::{workspace_root, Exercise, Outcome, EXERCISES};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/**
+ * Progress of every learner, persisted as JSON in the workspace root.
+ */
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct State {
+    pub users: BTreeMap<String, Progress>,
+}
+
+/// Progress of a single learner, keyed by exercise name.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Progress {
+    pub exercises: BTreeMap<String, ExerciseProgress>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ExerciseProgress {
+    pub completed: bool,
+    pub attempts: u32,
+    pub last_failure: Option<String>,
+    /// Seconds since the Unix epoch.
+    pub last_attempt_at: Option<u64>,
+    /// Seconds since the Unix epoch.
+    pub completed_at: Option<u64>,
+    #[serde(default)]
+    pub hints_used: u32,
+}
+
+impl State {
+    pub fn default_path() -> PathBuf {
+        workspace_root().join(".runner-state.json")
+    }
+
+    /// Loads the state file, or an empty state if it does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(io::Error::other),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    pub fn user(&mut self, name: &str) -> &mut Progress {
+        self.users.entry(name.to_owned()).or_default()
+    }
+}
+
+impl Progress {
+    pub fn get(&self, exercise: &Exercise) -> Option<&ExerciseProgress> {
+        self.exercises.get(exercise.name)
+    }
+
+    pub fn is_completed(&self, exercise: &Exercise) -> bool {
+        self.get(exercise).is_some_and(|p| p.completed)
+    }
+
+    /// Records one attempt at `exercise` at time `now`.
+    pub fn record(&mut self, exercise: &Exercise, outcome: &Outcome, now: u64) {
+        let progress = self.exercises.entry(exercise.name.to_owned()).or_default();
+
+        progress.attempts += 1;
+        progress.last_attempt_at = Some(now);
+
+        match outcome {
+            Outcome::Passed { .. } => {
+                progress.last_failure = None;
+                if !progress.completed {
+                    progress.completed = true;
+                    progress.completed_at = Some(now);
+                }
+            }
+            Outcome::Failed { output } | Outcome::CompileError { output } => {
+                progress.completed = false;
+                progress.completed_at = None;
+                progress.last_failure = Some(failure_summary(output));
+            }
+        }
+    }
+
+    pub fn hints_used(&self, exercise: &Exercise) -> u32 {
+        self.get(exercise).map_or(0, |p| p.hints_used)
+    }
+
+    /// Records that the first `level` hints of `exercise` have been revealed.
+    pub fn use_hints(&mut self, exercise: &Exercise, level: u32) {
+        let progress = self.exercises.entry(exercise.name.to_owned()).or_default();
+        progress.hints_used = progress.hints_used.max(level);
+    }
+
+    /// Forgets everything recorded for `exercise`. Returns `false` if there
+    /// was nothing to forget.
+    pub fn reset(&mut self, exercise: &Exercise) -> bool {
+        self.exercises.remove(exercise.name).is_some()
+    }
+
+    /// The first exercise, in registry order, that is not completed yet.
+    pub fn next_unfinished(&self) -> Option<&'static Exercise> {
+        EXERCISES.iter().find(|e| !self.is_completed(e))
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Formats seconds since the Unix epoch as `YYYY-MM-DD HH:MM` (UTC).
+pub fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let (hour, minute) = ((secs % 86_400) / 3600, (secs % 3600) / 60);
+
+    // Converts days since 1970-01-01 into a civil date (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}")
+}
+
+/// The most telling line of a failed run: the first compiler error, or the
+/// message of the first panicking test.
+pub fn failure_summary(output: &str) -> String {
+    let mut lines = output.lines();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("error") {
+            return line.to_owned();
+        }
+        if line.contains("panicked at") {
+            if let Some(message) = lines.next() {
+                return message.trim().to_owned();
+            }
+        }
+    }
+
+    "tests failed".to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed(output: &str) -> Outcome {
+        Outcome::Failed {
+            output: output.to_owned(),
+        }
+    }
+
+    #[test]
+    fn should_record_attempts_until_completed() {
+        let mut progress = Progress::default();
+        let exercise = &EXERCISES[0];
+
+        let output = "thread 'tests::test' panicked at src/a.rs:1:1:\nnot yet implemented\n";
+        progress.record(exercise, &failed(output), 10);
+        progress.record(exercise, &Outcome::Passed { tests: 1 }, 20);
+        progress.record(exercise, &Outcome::Passed { tests: 1 }, 30);
+
+        let expected = ExerciseProgress {
+            completed: true,
+            attempts: 3,
+            last_failure: None,
+            last_attempt_at: Some(30),
+            completed_at: Some(20),
+            hints_used: 0,
+        };
+        assert_eq!(progress.get(exercise), Some(&expected));
+    }
+
+    #[test]
+    fn should_keep_last_failure_message() {
+        let mut progress = Progress::default();
+        let exercise = &EXERCISES[0];
+
+        let output = "   Compiling m1_basics\nerror[E0308]: mismatched types\n";
+        progress.record(exercise, &failed(output), 10);
+
+        let actual = progress.get(exercise).unwrap().last_failure.as_deref();
+        assert_eq!(actual, Some("error[E0308]: mismatched types"));
+    }
+
+    #[test]
+    fn should_resume_at_first_unfinished_exercise() {
+        let mut progress = Progress::default();
+        assert_eq!(progress.next_unfinished().unwrap().name, EXERCISES[0].name);
+
+        progress.record(&EXERCISES[0], &Outcome::Passed { tests: 0 }, 10);
+        assert_eq!(progress.next_unfinished().unwrap().name, EXERCISES[1].name);
+
+        assert!(progress.reset(&EXERCISES[0]));
+        assert!(!progress.reset(&EXERCISES[0]));
+        assert_eq!(progress.next_unfinished().unwrap().name, EXERCISES[0].name);
+    }
+
+    #[test]
+    fn should_round_trip_through_state_file() {
+        let path = std::env::temp_dir().join(format!("runner-state-{}.json", std::process::id()));
+
+        let mut state = State::default();
+        state
+            .user("alice")
+            .record(&EXERCISES[0], &Outcome::Passed { tests: 0 }, 10);
+        state.save(&path).unwrap();
+
+        let mut loaded = State::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(loaded.user("alice").is_completed(&EXERCISES[0]));
+        assert!(!loaded.user("bob").is_completed(&EXERCISES[0]));
+    }
+
+    #[test]
+    fn should_format_timestamps_in_utc() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_709_210_096), "2024-02-29 12:34");
+    }
+}
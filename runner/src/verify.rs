@@ -0,0 +1,73 @@
+use crate::{
+    exercise::{Exercise, Outcome, EXERCISES},
+    state::{self, Progress},
+};
+use lib::delim;
+
+/**
+ * Runs every exercise in registry order and stops at the first one that
+ * fails. Exercises after the failing one are reported as not run.
+ *
+ * Every exercise that is run counts as an attempt in `progress`.
+ *
+ * Returns `true` if all exercises passed.
+ */
+pub fn verify(progress: &mut Progress) -> std::io::Result<bool> {
+    let mut results: Vec<(&Exercise, Option<Outcome>)> = Vec::new();
+    let mut failure = None;
+
+    for exercise in EXERCISES {
+        if failure.is_some() {
+            results.push((exercise, None));
+            continue;
+        }
+
+        println!("Verifying {} ...", exercise.name);
+        let outcome = exercise.run()?;
+        progress.record(exercise, &outcome, state::now());
+
+        if let Outcome::Failed { output } | Outcome::CompileError { output } = &outcome {
+            failure = Some((exercise, output.clone()));
+        }
+        results.push((exercise, Some(outcome)));
+    }
+
+    print_table(&results);
+
+    match failure {
+        Some((exercise, output)) => {
+            println!("{output}");
+            delim!();
+            println!(
+                "`{}` is not done yet: {}",
+                exercise.name,
+                exercise.path().display()
+            );
+            Ok(false)
+        }
+        None => {
+            println!("All exercises passed!");
+            Ok(true)
+        }
+    }
+}
+
+fn print_table(results: &[(&Exercise, Option<Outcome>)]) {
+    let mut package = "";
+
+    for (exercise, outcome) in results {
+        if exercise.package != package {
+            package = exercise.package;
+            delim!();
+            println!("{package}");
+            delim!();
+        }
+
+        let status = match outcome {
+            Some(outcome) => outcome.to_string(),
+            None => "-".to_string(),
+        };
+        println!("  {:<20}{status}", exercise.name);
+    }
+    delim!();
+}
@@ -0,0 +1,211 @@
+use crate::{
+    exercise::{self, Exercise, Outcome},
+    progress::unknown_exercise,
+    state::{self, State},
+};
+use lib::delim;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// How long the watched file may be missing before watching stops. Editors
+/// that save by renaming a temporary file over it remove it only briefly.
+const MISSING_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Detects changes of a single file by polling its modification time.
+struct Watcher {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+    /// When the file was first found missing, if it still is.
+    missing_since: Option<Instant>,
+    missing_timeout: Duration,
+}
+
+impl Watcher {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            last_modified: None,
+            missing_since: None,
+            missing_timeout: MISSING_TIMEOUT,
+        }
+    }
+
+    /// Returns `true` on the first call and whenever the file was saved since
+    /// the previous call. A file that cannot be read counts as unchanged,
+    /// until it has been missing for longer than `missing_timeout`.
+    fn changed(&mut self) -> io::Result<bool> {
+        let modified = match fs::metadata(&self.path).and_then(|m| m.modified()) {
+            Ok(modified) => modified,
+            Err(e) => {
+                let since = *self.missing_since.get_or_insert_with(Instant::now);
+                if since.elapsed() >= self.missing_timeout {
+                    return Err(e);
+                }
+                return Ok(false);
+            }
+        };
+        self.missing_since = None;
+        let changed = self.last_modified != Some(modified);
+        self.last_modified = Some(modified);
+        Ok(changed)
+    }
+}
+
+/**
+ * Re-runs the tests of the current exercise every time its file is saved.
+ *
+ * Without an explicit exercise name, watching starts at the first unfinished
+ * exercise and moves on to the next one as soon as it passes.
+ */
+pub fn watch(
+    state: &mut State,
+    state_path: &Path,
+    user: &str,
+    name: Option<&str>,
+) -> io::Result<bool> {
+    let mut exercise = match name {
+        Some(name) => exercise::find(name).ok_or_else(|| unknown_exercise(name))?,
+        None => match state.user(user).next_unfinished() {
+            Some(exercise) => exercise,
+            None => {
+                println!("All exercises are done!");
+                return Ok(true);
+            }
+        },
+    };
+
+    println!("Watching {} (Ctrl-C to stop)", exercise.path().display());
+    let mut watcher = Watcher::new(exercise.path());
+
+    loop {
+        if !watcher.changed()? {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+
+        let outcome = exercise.run()?;
+        state.user(user).record(exercise, &outcome, state::now());
+        state.save(state_path)?;
+
+        print_result(exercise, &outcome)?;
+
+        if outcome.is_success() && name.is_none() {
+            match state.user(user).next_unfinished() {
+                Some(next) => exercise = next,
+                None => {
+                    println!("All exercises are done!");
+                    return Ok(true);
+                }
+            }
+            println!("Watching {} (Ctrl-C to stop)", exercise.path().display());
+            watcher = Watcher::new(exercise.path());
+        }
+    }
+}
+
+fn print_result(exercise: &Exercise, outcome: &Outcome) -> io::Result<()> {
+    let todos = count_todos(&fs::read_to_string(exercise.path())?);
+
+    delim!();
+    match outcome {
+        Outcome::Passed { .. } => println!("{}: {outcome}", exercise.name),
+        Outcome::Failed { output } | Outcome::CompileError { output } => {
+            println!("{}: {outcome}", exercise.name);
+            println!("  {}", state::failure_summary(output));
+        }
+    }
+    println!("  todo!() left: {todos}");
+    delim!();
+
+    Ok(())
+}
+
+/// Counts the `todo!()` calls left in a source file, ignoring comments.
+fn count_todos(source: &str) -> usize {
+    source
+        .lines()
+        .map(|line| line.split("//").next().unwrap_or_default())
+        .map(|code| code.matches("todo!(").count())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn should_count_todos_outside_comments() {
+        let source = "\
+fn trim_me(input: &str) -> String {
+    // TODO: Remove whitespace from both ends of a string!
+    todo!()
+}
+// let v = todo!();
+let v = todo!(); // TODO: declare your vector here
+";
+        assert_eq!(count_todos(source), 2);
+    }
+
+    #[test]
+    fn should_notice_when_file_is_saved() {
+        let path = std::env::temp_dir().join(format!("runner-watch-{}.rs", std::process::id()));
+        fs::write(&path, "todo!()").unwrap();
+
+        let mut watcher = Watcher::new(path.clone());
+        assert!(watcher.changed().unwrap());
+        assert!(!watcher.changed().unwrap());
+
+        let later = SystemTime::now() + Duration::from_secs(60);
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert!(watcher.changed().unwrap());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn should_wait_for_file_replaced_by_rename() {
+        let path = std::env::temp_dir().join(format!("runner-rename-{}.rs", std::process::id()));
+        fs::write(&path, "todo!()").unwrap();
+
+        let mut watcher = Watcher::new(path.clone());
+        assert!(watcher.changed().unwrap());
+
+        fs::remove_file(&path).unwrap();
+        assert!(!watcher.changed().unwrap());
+        assert!(!watcher.changed().unwrap());
+
+        fs::write(&path, "()").unwrap();
+        let later = SystemTime::now() + Duration::from_secs(60);
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert!(watcher.changed().unwrap());
+        assert!(!watcher.changed().unwrap());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn should_give_up_on_file_missing_for_long() {
+        let path = std::env::temp_dir().join(format!("runner-missing-{}.rs", std::process::id()));
+
+        let mut watcher = Watcher::new(path);
+        watcher.missing_timeout = Duration::ZERO;
+        let error = watcher.changed().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
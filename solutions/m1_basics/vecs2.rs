@@ -0,0 +1,36 @@
+// A Vec of even numbers is given. Your task is to complete the loop
+// so that each number in the Vec is multiplied by 2.
+//
+// Make me pass the test!
+//
+
+fn vec_loop(mut v: Vec<i32>) -> Vec<i32> {
+    for element in v.iter_mut() {
+        *element *= 2;
+    }
+
+    // At this point, `v` should be equal to [4, 8, 12, 16, 20].
+    v
+}
+
+fn vec_map(v: &[i32]) -> Vec<i32> {
+    v.iter()
+        .map(|element| element * 2)
+        .collect()
+}
+
+#[test]
+fn test_vec_loop() {
+    let v: Vec<i32> = (1..).filter(|x| x % 2 == 0).take(5).collect();
+    let ans = vec_loop(v.clone());
+
+    assert_eq!(ans, v.iter().map(|x| x * 2).collect::<Vec<i32>>());
+}
+
+#[test]
+fn test_vec_map() {
+    let v: Vec<i32> = (1..).filter(|x| x % 2 == 0).take(5).collect();
+    let ans = vec_map(&v);
+
+    assert_eq!(ans, v.iter().map(|x| x * 2).collect::<Vec<i32>>());
+}
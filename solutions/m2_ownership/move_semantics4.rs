@@ -0,0 +1,13 @@
+// Make me compile only by reordering the lines in `test()`, but without
+// adding, changing or removing any of them.
+
+#[cfg(feature = "exercise-move_semantics4")]
+#[test]
+fn test1() {
+    let mut x = 100;
+    let y = &mut x;
+    *y += 100;
+    let z = &mut x;
+    *z += 1000;
+    assert_eq!(x, 1200);
+}
@@ -0,0 +1,26 @@
+// You can't change anything except adding or removing references.
+
+#[cfg(feature = "exercise-move_semantics5")]
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test() {
+        let data = "Rust is great!".to_string();
+
+        get_char(&data);
+
+        string_uppercase(data);
+    }
+
+    // Should not take ownership
+    fn get_char(data: &String) -> char {
+        data.chars().last().unwrap()
+    }
+
+    // Should take ownership
+    fn string_uppercase(mut data: String) {
+        data = data.to_uppercase();
+
+        println!("{}", data);
+    }
+}
@@ -0,0 +1,9 @@
+// error-code: E0502
+// From `m2_ownership::o3_borrowing_demo::borrow_test1`.
+
+fn borrow_test1() {
+    let mut d1 = 1;
+    let d2 = &mut d1;
+    let d3 = &d1; // Cannot borrow when already mutably borrowed
+    println!("{d2:?} {d3:?}");
+}
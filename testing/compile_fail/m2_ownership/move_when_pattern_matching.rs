@@ -0,0 +1,12 @@
+// error-code: E0382
+// From `m2_ownership::o5_miscellaneous::move_when_pattern_matching`.
+
+fn move_when_pattern_matching() {
+    let name: Option<String> = Some("Leto".to_string());
+
+    match name {
+        None => println!("no name"),
+        Some(name) => println!("we have a name: {}", name),
+    }
+    println!("{:?}", name)
+}
@@ -0,0 +1,13 @@
+// error-code: E0596
+// From `m2_ownership::o4_partial_moves::mutability_is_inherited`.
+
+struct User {
+    bookmarks: Vec<String>,
+}
+
+fn mutability_is_inherited() {
+    let user = User { bookmarks: vec![] };
+
+    // will not work as user isn't mutable
+    user.bookmarks.push("https://www.openmymind.net".to_owned());
+}
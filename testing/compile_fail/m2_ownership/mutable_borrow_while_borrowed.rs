@@ -0,0 +1,9 @@
+// error-code: E0502
+// From `m2_ownership::o3_borrowing_demo::borrow_test1`.
+
+fn borrow_test1() {
+    let mut c1 = 1;
+    let c2 = &c1;
+    let c3 = &mut c1; // Cannot mutably borrow when already borrowed
+    println!("{c2:?} {c3:?}");
+}
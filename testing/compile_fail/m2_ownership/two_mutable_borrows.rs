@@ -0,0 +1,9 @@
+// error-code: E0499
+// From `m2_ownership::o3_borrowing_demo::borrow_test1`.
+
+fn borrow_test1() {
+    let mut b1 = 1;
+    let b2 = &mut b1;
+    let b3 = &mut b1; // Cannot mutably borrow when already mutably borrowed
+    println!("{b2:?} {b3:?}");
+}
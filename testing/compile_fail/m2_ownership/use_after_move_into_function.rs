@@ -0,0 +1,18 @@
+// error-code: E0382
+// From `m2_ownership::o1_move_semantics::move_ownership_on_parameter_passing`.
+
+#[derive(Debug)]
+struct User {
+    id: u32,
+}
+
+fn move_ownership_on_parameter_passing() {
+    fn print_user(u: User) {
+        println!("{u:?}");
+    }
+
+    let u = User { id: 9000 };
+    print_user(u); // `u` is moved into the function
+
+    println!("{u:?}");
+}
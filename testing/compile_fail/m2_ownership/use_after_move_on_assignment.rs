@@ -0,0 +1,15 @@
+// error-code: E0382
+// From `m2_ownership::o1_move_semantics::move_ownership_on_assignment`.
+
+#[derive(Debug)]
+struct User {
+    id: u32,
+}
+
+fn move_ownership_on_assignment() {
+    let u1 = User { id: 9000 };
+    let u2 = u1; // `u1` is moved to `u2`
+    println!("{u2:?}");
+
+    println!("{:?}", u1);
+}
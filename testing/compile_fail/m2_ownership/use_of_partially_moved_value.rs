@@ -0,0 +1,25 @@
+// error-code: E0382
+// From `m2_ownership::o4_partial_moves::partial_move_test1`.
+
+#[derive(Debug)]
+struct Config {
+    max_bookmarks: usize,
+}
+
+#[derive(Debug)]
+struct User {
+    config: Config,
+    bookmarks: Vec<String>,
+}
+
+fn partial_move_test1() {
+    let user = User {
+        bookmarks: vec![],
+        config: Config {
+            max_bookmarks: 1000,
+        },
+    };
+
+    let config = user.config; // move `config` out of `user`
+    println!("{:?}, {:?}", user, config);
+}
@@ -0,0 +1,12 @@
+// error-code: E0308
+// From `m6_closures::c3_closure_types::capturing_closures_cannot_be_coerced_to_fn_pointers`.
+
+fn capturing_closures_cannot_be_coerced_to_fn_pointers() {
+    type Binop = fn(i32, i32) -> i32;
+
+    let k = 10;
+    let add = |x: i32, y: i32| x + y + k;
+
+    let bo: Binop = add;
+    println!("{}", bo(3, 4));
+}
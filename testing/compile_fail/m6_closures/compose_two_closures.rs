@@ -0,0 +1,14 @@
+// error-code: E0308
+// From `m6_closures::c3_closure_types::no_two_closures_have_the_same_type`.
+
+fn no_two_closures_have_the_same_type() {
+    fn compose<F>(x: i32, f: F, g: F) -> i32
+    where
+        F: Fn(i32) -> i32,
+    {
+        g(f(x))
+    }
+
+    let result = compose(5, |n: i32| n + n, |n: i32| n * n);
+    println!("{result}");
+}
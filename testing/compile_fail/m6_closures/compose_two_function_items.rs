@@ -0,0 +1,22 @@
+// error-code: E0308
+// From `m6_closures::c3_closure_types::no_two_function_items_have_the_same_type`.
+
+fn no_two_function_items_have_the_same_type() {
+    fn compose<F>(x: i32, f: F, g: F) -> i32
+    where
+        F: Fn(i32) -> i32,
+    {
+        g(f(x))
+    }
+
+    fn double(x: i32) -> i32 {
+        x + x
+    }
+
+    fn square(x: i32) -> i32 {
+        x * x
+    }
+
+    let result = compose(5, double, square); // what if casting both to fn(i32) -> i32?
+    println!("{result}")
+}
@@ -0,0 +1,13 @@
+// error-code: E0525
+// From `m6_closures::c3_closure_types::closures_may_implement_multiple_traits_case_for_fn_once_closure`.
+
+fn accept_fn<F: Fn() -> String>(f: F) -> String {
+    f()
+}
+
+fn fn_once_closure_as_fn() {
+    let s = String::from("FnOnce");
+    let closure = move || s; // moves `s` out, so it is only `FnOnce`
+
+    accept_fn(closure);
+}
@@ -0,0 +1,7 @@
+// error-code: E0666
+// From `m6_closures::c4_as_output_parameters::abstract_return_type_nested_impl_not_allowed`.
+
+// Only concrete types are supported as nested `impl Trait` args
+fn make_print() -> impl Fn(impl ToString) {
+    |text| println!("{}", text.to_string().to_uppercase())
+}
@@ -0,0 +1,10 @@
+// error-code: E0308
+// From `m6_closures::c4_as_output_parameters::returning_different_capturing_closures_not_allowed`.
+
+fn returns_closure(flag: bool, x: i32) -> impl Fn(i32) -> i32 {
+    if flag {
+        move |y| x + y
+    } else {
+        move |y| x * y
+    }
+}
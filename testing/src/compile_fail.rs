@@ -0,0 +1,185 @@
+//!
+//! # Compile-fail tests
+//!
+//! Some lessons are about code that must *not* compile. Such snippets live in
+//! `testing/compile_fail/<module>/*.rs` and start with a header naming the
+//! error codes `rustc` is expected to report:
+//!
+//! ```text
+//! // error-code: E0382
+//! ```
+//!
+//! Each snippet is compiled on its own as a library crate with the local
+//! `rustc` (or `$RUSTC`), and the check passes only if compilation fails with
+//! every expected error code.
+//!
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const HEADER: &str = "// error-code:";
+
+/// Error codes listed in the `// error-code:` header lines of a snippet.
+pub fn expected_codes(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix(HEADER))
+        .flat_map(|codes| codes.split(','))
+        .map(|code| code.trim().to_string())
+        .filter(|code| !code.is_empty())
+        .collect()
+}
+
+/// Error codes such as `E0382` found in `rustc`'s diagnostics.
+pub fn reported_codes(stderr: &str) -> Vec<String> {
+    let mut codes: Vec<String> = stderr
+        .lines()
+        .filter_map(|line| line.strip_prefix("error["))
+        .filter_map(|rest| rest.split_once(']'))
+        .map(|(code, _)| code.to_string())
+        .collect();
+
+    codes.sort();
+    codes.dedup();
+    codes
+}
+
+/// Compiles a single snippet and checks that it fails with the expected codes.
+pub fn check(snippet: &Path) -> Result<(), String> {
+    let source = fs::read_to_string(snippet)
+        .map_err(|e| format!("{}: cannot read snippet: {e}", snippet.display()))?;
+
+    let expected = expected_codes(&source);
+    if expected.is_empty() {
+        return Err(format!("{}: missing `{HEADER}` header", snippet.display()));
+    }
+
+    let out_dir = out_dir(snippet);
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let output = Command::new(rustc)
+        .args(["--edition", "2021", "--crate-type", "lib"])
+        .args(["--emit", "metadata", "-A", "warnings"])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg(snippet)
+        .output();
+    // rustc creates the directory only if it gets far enough to write to it.
+    let _ = fs::remove_dir_all(&out_dir);
+    let output = output.map_err(|e| format!("cannot run rustc: {e}"))?;
+
+    if output.status.success() {
+        return Err(format!(
+            "{}: compiled successfully, expected {}",
+            snippet.display(),
+            expected.join(", ")
+        ));
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let reported = reported_codes(&stderr);
+    let missing: Vec<_> = expected.iter().filter(|c| !reported.contains(c)).collect();
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}: expected {:?}, but rustc reported {:?}\n{stderr}",
+            snippet.display(),
+            missing,
+            reported
+        ))
+    }
+}
+
+/// A directory of its own for the output of compiling `snippet`, since
+/// snippets are checked in parallel and may share a file name across modules.
+fn out_dir(snippet: &Path) -> PathBuf {
+    let name = |path: Option<&Path>, part: fn(&Path) -> Option<&std::ffi::OsStr>| {
+        path.and_then(part)
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    };
+    env::temp_dir().join(format!(
+        "testing-compile-fail-{}-{}-{}",
+        std::process::id(),
+        name(snippet.parent(), Path::file_name),
+        name(Some(snippet), Path::file_stem),
+    ))
+}
+
+/// Checks every `*.rs` snippet in `dir`, reporting all failures at once.
+pub fn check_dir(dir: &Path) -> Result<usize, String> {
+    let snippets = snippets(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+
+    let failures: Vec<String> = snippets
+        .iter()
+        .filter_map(|snippet| check(snippet).err())
+        .collect();
+
+    if failures.is_empty() {
+        Ok(snippets.len())
+    } else {
+        Err(failures.join("\n"))
+    }
+}
+
+fn snippets(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "rs") {
+            paths.push(path);
+        }
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_expected_codes_from_header() {
+        let source = "// error-code: E0382\n// error-code: E0499, E0502\nfn main() {}";
+        assert_eq!(expected_codes(source), ["E0382", "E0499", "E0502"]);
+    }
+
+    #[test]
+    fn should_parse_reported_codes_from_diagnostics() {
+        let stderr = "\
+error[E0382]: borrow of moved value: `u1`
+error[E0382]: borrow of moved value: `u2`
+error: aborting due to 2 previous errors
+For more information about this error, try `rustc --explain E0382`.";
+
+        assert_eq!(reported_codes(stderr), ["E0382"]);
+    }
+
+    #[test]
+    fn should_remove_its_output_dir() {
+        let dir = env::temp_dir().join(format!("testing-snippets-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let failing = dir.join("moved.rs");
+        let passing = dir.join("fine.rs");
+        fs::write(
+            &failing,
+            "// error-code: E0382\npub fn f(s: String) { drop(s); drop(s); }",
+        )
+        .unwrap();
+        fs::write(&passing, "// error-code: E0382\npub fn f() {}").unwrap();
+
+        assert!(check(&failing).is_ok());
+        assert!(check(&passing).is_err());
+        assert!(!out_dir(&failing).exists());
+        assert!(!out_dir(&passing).exists());
+        assert_ne!(out_dir(&failing), out_dir(&passing));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -5,6 +5,8 @@
 //! assert_eq!(result, 4);
 //! ```
 
+pub mod compile_fail;
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
@@ -0,0 +1,23 @@
+use std::path::Path;
+use testing::compile_fail;
+
+fn check_module(module: &str) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("compile_fail")
+        .join(module);
+
+    match compile_fail::check_dir(&dir) {
+        Ok(count) => println!("{count} snippets in {module} failed to compile as expected"),
+        Err(e) => panic!("{e}"),
+    }
+}
+
+#[test]
+fn ownership_snippets_fail_to_compile() {
+    check_module("m2_ownership");
+}
+
+#[test]
+fn closure_snippets_fail_to_compile() {
+    check_module("m6_closures");
+}